@@ -1,11 +1,12 @@
 use std::borrow::Cow;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt::{Formatter, Write};
 
 use indenter::CodeFormatter;
 use itertools::Itertools;
 
 use crate::parser;
+use crate::tables;
 
 type Key = String;
 type Field = String;
@@ -15,14 +16,68 @@ pub struct CodeGenerator {
     by_language: BTreeMap<Lang, BTreeMap<Key, Category>>,
     field_metadata: BTreeMap<Key, BTreeMap<Field, Meta>>,
     normalized_langs: BTreeMap<Lang, String>,
+    components: BTreeMap<Lang, Components>,
+    defined_categories: BTreeMap<Lang, BTreeSet<Key>>,
+    copies: BTreeMap<Lang, Vec<Lang>>,
+    copy_errors: Vec<CopyError>,
 }
 
+/// A `copy` of a category that can't be resolved. The category is generated without items
+/// instead, so the other locales are still generated.
+#[derive(Debug, PartialEq)]
+pub enum CopyError {
+    /// The category `category` of `lang` copies `target`, which is not a locale.
+    UnknownLocale {
+        lang: String,
+        category: String,
+        target: String,
+    },
+    /// The category `category` of `lang` copies itself through the locales of `chain`, which
+    /// starts and ends with `lang`.
+    Cycle {
+        lang: String,
+        category: String,
+        chain: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for CopyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CopyError::UnknownLocale {
+                lang,
+                category,
+                target,
+            } => write!(
+                f,
+                "{} of {} copies the unknown locale {:?}",
+                category, lang, target
+            ),
+            CopyError::Cycle {
+                lang,
+                category,
+                chain,
+            } => write!(
+                f,
+                "{} of {} copies itself: {}",
+                category,
+                lang,
+                chain.join(" -> ")
+            ),
+        }
+    }
+}
+
+/// Language, territory and modifier of a locale name: `language[_territory][@modifier]`.
+type Components = (String, Option<String>, Option<String>);
+
 enum Category {
-    Link(String, String),
+    /// A re-export of the same category of the locale with this normalized name.
+    Link(String),
     Fields(BTreeMap<Field, Value>),
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 enum Value {
     Empty,
     Literal(String),
@@ -32,31 +87,43 @@ enum Value {
 
 struct TypeFormatter<'a> {
     meta: &'a Meta,
+    lifetime: &'static str,
 }
 
 impl<'a> TypeFormatter<'a> {
     fn new(meta: &'a Meta) -> Self {
-        Self { meta }
+        Self { meta, lifetime: "" }
+    }
+
+    /// Format the type with explicit `'static` lifetimes, as needed for struct fields.
+    fn with_static_lifetime(meta: &'a Meta) -> Self {
+        Self {
+            meta,
+            lifetime: "'static ",
+        }
     }
 }
 
 impl<'a> std::fmt::Display for TypeFormatter<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lt = self.lifetime;
         match &self.meta.ty {
             None => unreachable!(),
             Some(ty) => {
+                let ty = match ty {
+                    Type::String => format!("&{}str", lt),
+                    Type::Integer => ty.to_string(),
+                };
+                let ty = match (self.meta.container_ty, self.meta.fixed_len) {
+                    (ContainerType::Singleton, _) => ty,
+                    (ContainerType::Array, Some(len)) => format!("&{}[{}; {}]", lt, ty, len),
+                    (ContainerType::Array, None) => format!("&{}[{}]", lt, ty),
+                    (ContainerType::Array2D, _) => format!("&{}[&{}[{}]]", lt, lt, ty),
+                };
                 if self.meta.optional {
-                    match self.meta.container_ty {
-                        ContainerType::Singleton => write!(f, "Option<{}>", ty),
-                        ContainerType::Array => write!(f, "Option<&[{}]>", ty),
-                        ContainerType::Array2D => write!(f, "Option<&[&[{}]]>", ty),
-                    }
+                    write!(f, "Option<{}>", ty)
                 } else {
-                    match self.meta.container_ty {
-                        ContainerType::Singleton => write!(f, "{}", ty),
-                        ContainerType::Array => write!(f, "&[{}]", ty),
-                        ContainerType::Array2D => write!(f, "&[&[{}]]", ty),
-                    }
+                    write!(f, "{}", ty)
                 }
             }
         }
@@ -184,6 +251,7 @@ impl Value {
         &self,
         field_name: &str,
         meta: &Meta,
+        shared: Option<&str>,
         f: &mut CodeFormatter<W>,
     ) -> std::fmt::Result {
         let ty = meta.ty.as_ref().unwrap();
@@ -232,57 +300,129 @@ impl Value {
             }
         }
 
-        write!(
-            f,
-            r#"
-            pub const {key}: {ty} = {val};
-            "#,
-            key = field_name,
-            ty = type_formatter,
-            val = value_formatter,
-        )?;
+        match shared {
+            Some(path) if meta.optional => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = Some({path});
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                path = path,
+            )?,
+            Some(path) => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = {path};
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                path = path,
+            )?,
+            None => write!(
+                f,
+                r#"
+                pub const {key}: {ty} = {val};
+                "#,
+                key = field_name,
+                ty = type_formatter,
+                val = value_formatter,
+            )?,
+        }
 
         Ok(())
     }
+
+    /// The type and the literal of a slice value, as a key to share identical slices.
+    fn slice_key(&self, meta: &Meta) -> Option<(String, String)> {
+        struct Literal<'a>(&'a Value, &'a Type);
+
+        impl<'a> std::fmt::Display for Literal<'a> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                ValueFormatter::format(f, self.0, self.1)
+            }
+        }
+
+        match self {
+            Value::Array(_) | Value::Array2d(_) => {
+                let ty = TypeFormatter::new(meta).to_string();
+                let ty = match meta.optional {
+                    true => ty["Option<".len()..ty.len() - 1].to_string(),
+                    false => ty,
+                };
+                Some((ty, Literal(self, meta.ty.as_ref()?).to_string()))
+            }
+            _ => None,
+        }
+    }
 }
 
 impl CodeGenerator {
     pub fn new(objects: HashMap<String, Vec<parser::Object>>) -> Self {
+        Self::with_sorted_rows(objects, &[])
+    }
+
+    /// Generate the locales of `objects` with the rows of the items of `sorted_rows` sorted, see
+    /// [`SortedRows`]. All other items keep the order of the source files.
+    pub fn with_sorted_rows(
+        objects: HashMap<String, Vec<parser::Object>>,
+        sorted_rows: &[SortedRows],
+    ) -> Self {
         let mut by_language = BTreeMap::<Lang, BTreeMap<Key, Category>>::new();
         let mut field_metadata = BTreeMap::<Key, BTreeMap<Field, Meta>>::new();
         let mut normalized_langs = BTreeMap::<Lang, String>::new();
+        let mut components = BTreeMap::<Lang, Components>::new();
+        let mut defined_categories = BTreeMap::<Lang, BTreeSet<Key>>::new();
+        let mut copies = BTreeMap::<Lang, Vec<Lang>>::new();
 
         for (lang, objects) in objects.iter() {
             normalized_langs.insert(lang.to_string(), lang.replace('@', "_"));
 
+            let (language, territory, modifier) =
+                parser::parse_lang(lang).expect("locale name has already been validated");
+            components.insert(
+                lang.to_string(),
+                (
+                    language.to_string(),
+                    territory.map(str::to_string),
+                    modifier.map(str::to_string),
+                ),
+            );
+
+            defined_categories.insert(
+                lang.to_string(),
+                objects.iter().map(|x| x.name.clone()).collect(),
+            );
+
+            copies.insert(
+                lang.to_string(),
+                objects
+                    .iter()
+                    .filter_map(|x| match x.values.as_slice() {
+                        [(key, value)] if key == "copy" => match value.as_slice() {
+                            [parser::Value::String(x)] => Some(x.clone()),
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect(),
+            );
+
             let lang_categories = by_language.entry(lang.to_string()).or_default();
 
             for object in objects.iter() {
-                if object.name == "LC_COLLATE"
-                    || object.name == "LC_CTYPE"
-                    || object.name == "LC_MEASUREMENT"
-                    || object.name == "LC_PAPER"
-                    || object.name == "LC_NAME"
-                {
+                if object.name == "LC_COLLATE" || object.name == "LC_CTYPE" {
                     continue;
-                } else if object.values.len() == 1 {
+                } else if object.values.len() == 1 && object.values[0].0 == "copy" {
                     let (key, value) = &object.values[0];
-                    #[allow(clippy::single_match)]
-                    match key.as_str() {
-                        "copy" => {
-                            assert_eq!(value.len(), 1);
-
-                            match &value[0] {
-                                parser::Value::String(x) => {
-                                    lang_categories.insert(
-                                        object.name.clone(),
-                                        Category::Link(x.replace('@', "_"), object.name.clone()),
-                                    );
-                                }
-                                x => panic!("unexpected value for key {}: {:?}", key, x),
-                            }
+                    assert_eq!(value.len(), 1);
+
+                    match &value[0] {
+                        parser::Value::String(x) => {
+                            lang_categories
+                                .insert(object.name.clone(), Category::Link(x.replace('@', "_")));
                         }
-                        _ => {}
+                        x => panic!("unexpected value for key {}: {:?}", key, x),
                     }
                     continue;
                 }
@@ -291,6 +431,9 @@ impl CodeGenerator {
 
                 let cat_field_meta = field_metadata.entry(object.name.clone()).or_default();
 
+                // NOTE: the sort is stable, so the rows of a key that appears several times (like
+                //       `category`) and the values within a row (like the 12 months of `mon`) keep
+                //       the order of the source file, unless they are in `sorted_rows`.
                 for (key, group) in &object
                     .values
                     .iter()
@@ -304,9 +447,12 @@ impl CodeGenerator {
                         .replace('=', "eq")
                         .replace('<', "lt")
                         .replace("..", "dotdot")
-                        .replace('2', "two")
                         .to_uppercase();
                     let group: Vec<_> = group.map(|x| &x.1).collect();
+                    let compare = sorted_rows
+                        .iter()
+                        .find(|x| x.category == object.name && x.item == key)
+                        .map(|x| x.compare);
 
                     let meta = cat_field_meta.entry(key.clone()).or_insert(Meta::new());
 
@@ -322,23 +468,27 @@ impl CodeGenerator {
                         }
 
                         fields.insert(key, Value::Literal(singleton.to_string()));
-                    } else if group.len() == 1 && group[0].iter().map(u8::from).all_equal() {
+                    } else if group.len() == 1 {
+                        // NOTE: none of the generated categories mixes strings and integers in one
+                        // item, but if one does the integers are written as strings, like `"1"`.
                         let values = &group[0];
-                        let vec = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                        let mut vec = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+
+                        if let Some(compare) = compare {
+                            vec.sort_by(|a, b| compare(a, b));
+                        }
 
                         meta.mark_array();
 
-                        match &values[0] {
-                            parser::Value::Raw(_) | parser::Value::String(_) => meta.mark_str(),
-                            parser::Value::Integer(_) => meta.mark_int(),
+                        for value in values.iter() {
+                            match value {
+                                parser::Value::Raw(_) | parser::Value::String(_) => meta.mark_str(),
+                                parser::Value::Integer(_) => meta.mark_int(),
+                            }
                         }
 
                         fields.insert(key, Value::Array(vec));
-                    } else if group
-                        .iter()
-                        .flat_map(|x| x.iter().map(u8::from))
-                        .all_equal()
-                    {
+                    } else {
                         meta.mark_array_2d();
 
                         let mut vec = Vec::with_capacity(group.len());
@@ -358,9 +508,11 @@ impl CodeGenerator {
                             vec.push(inner_vec);
                         }
 
+                        if let Some(compare) = compare {
+                            vec.sort_by(|a: &Vec<String>, b: &Vec<String>| compare(&a[0], &b[0]));
+                        }
+
                         fields.insert(key, Value::Array2d(vec));
-                    } else {
-                        unimplemented!()
                     }
                 }
 
@@ -368,6 +520,61 @@ impl CodeGenerator {
             }
         }
 
+        let mut copy_errors = Vec::new();
+        for (lang, categories) in by_language.iter_mut() {
+            for (category_name, category) in categories.iter_mut() {
+                match category {
+                    Category::Link(other) if !normalized_langs.values().any(|x| x == other) => {
+                        copy_errors.push(CopyError::UnknownLocale {
+                            lang: lang.clone(),
+                            category: category_name.clone(),
+                            target: other.clone(),
+                        });
+                        *category = Category::Fields(BTreeMap::new());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // a `copy` of a `copy` is a re-export of a re-export, but a cycle can't be generated
+        let langs: HashMap<&String, &Lang> = normalized_langs.iter().map(|(k, v)| (v, k)).collect();
+        let links: Vec<(Lang, Key)> = by_language
+            .iter()
+            .flat_map(|(lang, categories)| {
+                categories.keys().map(move |x| (lang.clone(), x.clone()))
+            })
+            .collect();
+        for (lang, category_name) in links {
+            let mut chain = vec![normalized_langs[&lang].clone()];
+            let mut current = &lang;
+            // a cycle that doesn't go through `lang` is found from one of its own locales
+            let cycle = loop {
+                match by_language[current].get(&category_name) {
+                    Some(Category::Link(other)) if chain.contains(other) => {
+                        break *other == chain[0];
+                    }
+                    Some(Category::Link(other)) => {
+                        chain.push(other.clone());
+                        current = langs[other];
+                    }
+                    _ => break false,
+                }
+            };
+            if cycle {
+                chain.push(chain[0].clone());
+                copy_errors.push(CopyError::Cycle {
+                    lang: lang.clone(),
+                    category: category_name.clone(),
+                    chain,
+                });
+                by_language
+                    .get_mut(&lang)
+                    .unwrap()
+                    .insert(category_name, Category::Fields(BTreeMap::new()));
+            }
+        }
+
         for (_lang, categories) in by_language.iter_mut() {
             for (category_name, all_fields) in field_metadata.iter_mut() {
                 let language_cats = categories
@@ -375,7 +582,7 @@ impl CodeGenerator {
                     .or_insert(Category::Fields(BTreeMap::new()));
 
                 match language_cats {
-                    Category::Link(_, _) => {}
+                    Category::Link(_) => {}
                     Category::Fields(fields) => {
                         for (field, meta) in all_fields {
                             if fields.get(field).is_none() {
@@ -388,18 +595,79 @@ impl CodeGenerator {
             }
         }
 
+        for (category_name, field_name) in EMPTY_WHEN_MISSING {
+            let meta = match field_metadata
+                .get_mut(*category_name)
+                .and_then(|x| x.get_mut(*field_name))
+            {
+                Some(meta) if matches!(meta.container_ty, ContainerType::Array) => meta,
+                _ => continue,
+            };
+            meta.optional = false;
+            for categories in by_language.values_mut() {
+                if let Some(Category::Fields(fields)) = categories.get_mut(*category_name) {
+                    if let Some(value @ Value::Empty) = fields.get_mut(*field_name) {
+                        *value = Value::Array(Vec::new());
+                    }
+                }
+            }
+        }
+
+        for (category_name, field_name, len) in FIXED_LENGTHS {
+            let meta = match field_metadata
+                .get_mut(*category_name)
+                .and_then(|x| x.get_mut(*field_name))
+            {
+                Some(meta) => meta,
+                None => continue,
+            };
+            let fixed =
+                by_language
+                    .values()
+                    .all(|categories| match categories.get(*category_name) {
+                        Some(Category::Fields(fields)) => match fields.get(*field_name) {
+                            Some(Value::Array(x)) => x.len() == *len,
+                            Some(Value::Empty) | None => true,
+                            Some(_) => false,
+                        },
+                        _ => true,
+                    });
+            if fixed && matches!(meta.container_ty, ContainerType::Array) {
+                meta.fixed_len = Some(*len);
+            }
+        }
+
+        dedup_categories(&mut by_language, &normalized_langs);
+
         Self {
             by_language,
             field_metadata,
             normalized_langs,
+            components,
+            defined_categories,
+            copies,
+            copy_errors,
         }
     }
 
+    /// The `copy`s that couldn't be resolved, see [`CopyError`].
+    pub fn copy_errors(&self) -> &[CopyError] {
+        &self.copy_errors
+    }
+
     fn generate<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
         write!(
             f,
             r#"
             #![no_std]
+            // the helpers and shared slices used only by disabled categories or locales are unused
+            #![cfg_attr(not(all(feature = "all-categories", feature = "all-locales")), allow(dead_code))]
+
+            #[cfg(feature = "alloc")]
+            extern crate alloc;
+
+            #[cfg(feature = "std")]
+            extern crate std;
 
             #[derive(Debug)]
             pub struct UnknownLocale;
@@ -407,60 +675,65 @@ impl CodeGenerator {
             "#,
         )?;
 
+        self.generate_helpers(f)?;
+        let slices = self.generate_slices(f)?;
+        self.generate_types(f)?;
+
+        write!(
+            f,
+            r#"
+
+            /// The modules of all the locales, like `locales::en_US`.
+            ///
+            /// With the default feature `root-modules` they are also available at the root of the
+            /// crate, like `en_US`. Disable it to keep them out of glob imports of the crate.
+            pub mod locales {{
+            "#,
+        )?;
+        f.indent(1);
+
+        let shared = self.shared_categories();
+
         for (lang, categories) in self.by_language.iter() {
-            let lang = &self.normalized_langs[lang];
+            let norm = &self.normalized_langs[lang];
 
             write!(
                 f,
                 r#"
 
                 #[allow(non_snake_case,non_camel_case_types,dead_code,unused_imports)]
+                "#,
+            )?;
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
                 pub mod {} {{
                 "#,
-                lang,
+                norm,
             )?;
             f.indent(1);
 
             for (category_name, category) in categories.iter() {
-                let category_metadata = self.field_metadata.get(category_name).unwrap();
-
+                let root = self.link_root(lang, category_name);
                 match category {
-                    Category::Link(lang, category_name) => {
-                        write!(
-                            f,
-                            r#"
-                            pub use super::{}::{};
-                            "#,
-                            lang, category_name,
-                        )?;
+                    Category::Fields(fields)
+                        if root == *norm
+                            && !shared.contains_key(&(root.clone(), category_name.clone())) =>
+                    {
+                        self.generate_category(lang, category_name, fields, &slices, f)?;
                     }
-                    Category::Fields(fields) => {
+                    _ => {
                         write!(
                             f,
                             r#"
-                            pub mod {} {{
+                            #[cfg(feature = "{}")]
+                            pub use crate::shared::{}::{};
                             "#,
+                            category_feature(category_name),
+                            root,
                             category_name,
                         )?;
-
-                        f.indent(1);
-
-                        for (field_name, meta) in category_metadata.iter() {
-                            fields
-                                .get(field_name)
-                                .unwrap()
-                                .with_fixed_type(meta)
-                                .generate(field_name, meta, f)?;
-                        }
-
-                        f.dedent(1);
-
-                        write!(
-                            f,
-                            r#"
-                            }}
-                            "#,
-                        )?;
                     }
                 }
             }
@@ -475,7 +748,84 @@ impl CodeGenerator {
             )?
         }
 
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            #[cfg(feature = "root-modules")]
+            pub use locales::*;
+
+            /// The categories that are used by several locales, re-exported by each of them. They are
+            /// enabled if at least one of these locales is.
+            #[doc(hidden)]
+            pub mod shared {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (root, categories) in &shared.iter().group_by(|((root, _), _)| root) {
+            let categories = categories.collect::<Vec<_>>();
+            let users = categories
+                .iter()
+                .flat_map(|(_, users)| users.iter().map(|x| x.as_str()))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+            write!(
+                f,
+                r#"
+
+                #[allow(non_snake_case,non_camel_case_types,dead_code,unused_imports)]
+                "#,
+            )?;
+            self.write_locale_cfg(&users, f)?;
+            write!(
+                f,
+                r#"
+                pub mod {} {{
+                "#,
+                root,
+            )?;
+            f.indent(1);
+
+            let (lang, _) = self
+                .normalized_langs
+                .iter()
+                .find(|(_, x)| *x == root)
+                .unwrap();
+            for ((_, category_name), users) in categories {
+                let fields = match &self.by_language[lang][category_name] {
+                    Category::Fields(fields) => fields,
+                    Category::Link(_) => unreachable!(),
+                };
+                let users = users.iter().map(|x| x.as_str()).collect::<Vec<_>>();
+                self.write_locale_cfg(&users, f)?;
+                self.generate_category(lang, category_name, fields, &slices, f)?;
+            }
+
+            f.dedent(1);
+            write!(
+                f,
+                r#"
+                }}
+                "#,
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+            "#,
+        )?;
+
         self.generate_variants(f)?;
+        self.generate_locale_impl(f)?;
+        self.generate_lc_time_struct(f)?;
+        self.generate_locale_data(f)?;
 
         write!(
             f,
@@ -491,158 +841,3651 @@ impl CodeGenerator {
         Ok(())
     }
 
-    fn generate_variants<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+    /// The features of the categories and the locales, for the `[features]` of `Cargo.toml`.
+    pub fn cargo_features(&self) -> String {
+        let list = |features: &[String]| {
+            features
+                .iter()
+                .map(|x| format!("    {:?},\n", x))
+                .collect::<String>()
+        };
+        let categories = self
+            .field_metadata
+            .keys()
+            .map(|x| category_feature(x))
+            .collect::<Vec<_>>();
+        let locales = self
+            .normalized_langs
+            .values()
+            .filter(|x| *x != "POSIX")
+            .map(|x| locale_feature(x))
+            .collect::<Vec<_>>();
+
+        let mut features = format!("all-categories = [\n{}]\n", list(&categories));
+        for feature in &categories {
+            features += &format!("{} = []\n", feature);
+        }
+        features += &format!("all-locales = [\n{}]\n", list(&locales));
+        for feature in &locales {
+            features += &format!("{} = []\n", feature);
+        }
+        features
+    }
+
+    /// The module of the category `category_name` with the values `fields`.
+    fn generate_category<W: Write>(
+        &self,
+        lang: &str,
+        category_name: &str,
+        fields: &BTreeMap<Field, Value>,
+        slices: &HashMap<(String, String), usize>,
+        f: &mut CodeFormatter<W>,
+    ) -> std::fmt::Result {
+        let category_metadata = self.field_metadata.get(category_name).unwrap();
+
         write!(
             f,
             r#"
-
-            /// Locales matching the locales in `glibc`.
-            ///
-            /// Most locale names follow the syntax `language[_territory][@modifier]`.
-            /// The `@` is replaced with `_` in the `enum` variant names.
-            ///
-            /// The default locale is `POSIX`.
-            ///
-            /// License note: The Free Software Foundation does not claim any copyright interest in the locale
-            /// data of the GNU C Library; they believe it is not copyrightable.
-            #[allow(non_camel_case_types,dead_code)]
-            #[derive(Copy, Clone, PartialEq, Eq, Hash)]
-            pub enum Locale {{
+            #[cfg(feature = "{}")]
+            pub mod {} {{
             "#,
+            category_feature(category_name),
+            category_name,
         )?;
+
         f.indent(1);
 
-        for (lang, norm) in self.normalized_langs.iter() {
-            let desc = match self
-                .by_language
-                .get(lang)
-                .and_then(|l| l.get("LC_IDENTIFICATION"))
-            {
-                Some(Category::Fields(fields)) => match fields.get("TITLE") {
-                    Some(Value::Literal(title)) => {
-                        let mut title = title.clone();
-                        if !title.ends_with('.') {
-                            title.push('.');
-                        }
-                        title
-                    }
-                    _ => match lang == "POSIX" {
-                        true => "POSIX Standard Locale.".to_string(),
-                        false => "".to_string(),
-                    },
-                },
-                _ => "".to_string(),
-            };
-            write!(f, "\n/// `{}`: {}\n", lang, desc)?;
-            writeln!(f, "\n{},\n", norm)?;
+        for (field_name, meta) in category_metadata.iter() {
+            let value = fields.get(field_name).unwrap().with_fixed_type(meta);
+            let shared = value
+                .slice_key(meta)
+                .and_then(|x| slices.get(&x))
+                .map(|i| format!("crate::slices::SLICE_{}", i));
+            value.generate(field_name, meta, shared.as_deref(), f)?;
         }
 
-        f.dedent(1);
         write!(
             f,
             r#"
-            }}
 
-            impl core::fmt::Display for Locale {{
-                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                    f.write_str(match self {{
+            /// All the items of this category, for `Locale::typed_entries` and
+            /// `Locale::to_posix_source`.
+            pub(crate) const ITEMS: &[(&str, crate::helpers::Item)] = &[
             "#,
         )?;
-        f.indent(3);
-
-        for (lang, norm) in self.normalized_langs.iter() {
+        f.indent(1);
+        for (field_name, meta) in category_metadata.iter() {
+            let value = match meta.optional {
+                true => field_name.to_string(),
+                false => format!("Some({})", field_name),
+            };
             write!(
                 f,
                 r#"
-                Locale::{norm} => {lang:?},
+                ({:?}, crate::helpers::Item::{}({})),
                 "#,
-                lang = lang,
-                norm = norm,
+                field_name,
+                meta.item_variant(),
+                value,
             )?;
         }
-
-        f.dedent(3);
+        f.dedent(1);
         write!(
             f,
             r#"
-                    }})
-                }}
-            }}
+            ];
+            "#,
+        )?;
 
-            impl core::fmt::Debug for Locale {{
-                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
-                    core::fmt::Display::fmt(self, f)
-                }}
-            }}
+        self.generate_category_helpers(lang, category_name, fields, f)?;
 
-            impl core::str::FromStr for Locale {{
-                type Err = UnknownLocale;
+        f.dedent(1);
 
-                fn from_str(s: &str) -> Result<Self, Self::Err> {{
-                    core::convert::TryFrom::<&str>::try_from(s)
-                }}
+        write!(
+            f,
+            r#"
             }}
-
-            impl core::convert::TryFrom<&str> for Locale {{
-                type Error = UnknownLocale;
-
-                fn try_from(i: &str) -> Result<Self, Self::Error> {{
-                    match i {{
             "#,
-        )?;
-        f.indent(3);
+        )
+    }
 
-        for (lang, norm) in self.normalized_langs.iter() {
-            write!(
+    /// Write the attribute that enables an item only with the feature of one of the locales
+    /// `norms`. Nothing is written if one of them is `POSIX`, which is always enabled.
+    fn write_locale_cfg<W: Write>(
+        &self,
+        norms: &[&str],
+        f: &mut CodeFormatter<W>,
+    ) -> std::fmt::Result {
+        if norms.contains(&"POSIX") {
+            return Ok(());
+        }
+        let features = norms
+            .iter()
+            .map(|x| format!("feature = \"{}\"", locale_feature(x)));
+        match norms {
+            [_] => write!(f, "\n#[cfg({})]\n", features.format("")),
+            _ => write!(f, "\n#[cfg(any({}))]\n", features.format(", ")),
+        }
+    }
+
+    /// The normalized name of the locale whose category `category_name` the category of `lang`
+    /// re-exports, following the links, or of `lang` itself.
+    fn link_root(&self, lang: &str, category_name: &str) -> String {
+        match &self.by_language[lang][category_name] {
+            Category::Fields(_) => self.normalized_langs[lang].clone(),
+            Category::Link(other) => {
+                let (other, _) = self
+                    .normalized_langs
+                    .iter()
+                    .find(|(_, x)| *x == other)
+                    .unwrap_or_else(|| panic!("unknown locale {:?}", other));
+                self.link_root(other, category_name)
+            }
+        }
+    }
+
+    /// The categories that several locales re-export, by their locale and name, with the
+    /// normalized names of all these locales.
+    fn shared_categories(&self) -> BTreeMap<(String, Key), BTreeSet<String>> {
+        let mut shared = BTreeMap::<(String, Key), BTreeSet<String>>::new();
+        for (lang, categories) in self.by_language.iter() {
+            for (category_name, category) in categories.iter() {
+                if let Category::Link(_) = category {
+                    let root = self.link_root(lang, category_name);
+                    let users = shared
+                        .entry((root.clone(), category_name.clone()))
+                        .or_default();
+                    users.insert(root);
+                    users.insert(self.normalized_langs[lang].clone());
+                }
+            }
+        }
+        shared
+    }
+
+    fn generate_variants<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+
+            /// Locales matching the locales in `glibc`.
+            ///
+            /// Most locale names follow the syntax `language[_territory][@modifier]`.
+            /// The `@` is replaced with `_` in the `enum` variant names.
+            ///
+            /// The default locale is `POSIX`.
+            ///
+            /// `Locale` is a field-less `enum` that fits in 2 bytes. The unused values are available as
+            /// niche, so `Option<Locale>` has the same size as `Locale`.
+            ///
+            /// The hash of a `Locale` is the hash of its name. It is the same on all platforms and
+            /// doesn't change when locales are added or removed.
+            ///
+            /// Locales are ordered by their glibc name, like `de_DE` < `de_DE@euro` < `en_US`. As with
+            /// `str` uppercase sorts before lowercase, so `POSIX` comes first.
+            ///
+            /// License note: The Free Software Foundation does not claim any copyright interest in the locale
+            /// data of the GNU C Library; they believe it is not copyrightable.
+            #[allow(non_camel_case_types,dead_code)]
+            #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+            pub enum Locale {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            let mut desc = self.title(lang);
+            if !desc.is_empty() && !desc.ends_with('.') {
+                desc.push('.');
+            }
+            write!(f, "\n/// `{}`: {}\n", lang, desc)?;
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            writeln!(f, "\n{},\n", norm)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            /// `Some` of every locale if its feature is enabled, or `None`, to filter the lists of
+            /// locales.
+            #[allow(non_upper_case_globals)]
+            mod enabled {{
+                use crate::Locale;
+            "#,
+        )?;
+        f.indent(1);
+
+        for norm in self.normalized_langs.values() {
+            if norm == "POSIX" {
+                write!(
+                    f,
+                    "\npub(crate) const POSIX: Option<Locale> = Some(Locale::POSIX);\n"
+                )?;
+                continue;
+            }
+            write!(
+                f,
+                r#"
+                #[cfg(feature = "{feature}")]
+                pub(crate) const {norm}: Option<Locale> = Some(Locale::{norm});
+                #[cfg(not(feature = "{feature}"))]
+                pub(crate) const {norm}: Option<Locale> = None;
+                "#,
+                feature = locale_feature(norm),
+                norm = norm,
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+
+            /// All the enabled variants of [`Locale`], sorted by name.
+            const VARIANTS: &[Locale] =
+                &crate::helpers::enabled::<{{ crate::helpers::count_enabled(MAYBE_VARIANTS) }}>(MAYBE_VARIANTS);
+
+            const MAYBE_VARIANTS: &[Option<Locale>] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        for norm in self.normalized_langs.values() {
+            write!(f, "\nenabled::{},\n", norm)?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            /// All the enabled locales that define eras in `LC_TIME::ERA`, like `ja_JP`, sorted by
+            /// name.
+            pub const ERA_LOCALES: &[Locale] =
+                &crate::helpers::enabled::<{{ crate::helpers::count_enabled(MAYBE_ERA_LOCALES) }}>(MAYBE_ERA_LOCALES);
+
+            const MAYBE_ERA_LOCALES: &[Option<Locale>] = &[
+            "#,
+        )?;
+        f.indent(1);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            let has_era = match self.fields(lang, "LC_TIME").and_then(|x| x.get("ERA")) {
+                Some(Value::Literal(x)) => !x.is_empty(),
+                Some(Value::Array(x)) => !x.is_empty(),
+                Some(Value::Array2d(x)) => !x.is_empty(),
+                Some(Value::Empty) | None => false,
+            };
+            if has_era {
+                write!(f, "\nenabled::{},\n", norm)?;
+            }
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            ];
+
+            impl Locale {{
+                /// Returns the name of the locale, like `"de_DE@euro"`. This is the same as the
+                /// `Display` implementation, but can be used in a `const` context.
+                pub const fn as_str(&self) -> &'static str {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {lang:?},
+                "#,
+                lang = lang,
+                norm = norm,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// Returns the name of the locale as bytes, like `b"de_DE@euro"`.
+                pub const fn name_bytes(&self) -> &'static [u8] {{
+                    self.as_str().as_bytes()
+                }}
+
+                /// All the locales, sorted by name.
+                pub const ALL: &'static [Locale] = VARIANTS;
+
+                /// The number of locales, the length of [`ALL`](Locale::ALL).
+                pub const COUNT: usize = VARIANTS.len();
+
+                /// The index of this locale in [`ALL`](Locale::ALL), below [`COUNT`](Locale::COUNT).
+                ///
+                /// The index depends on the enabled locales, so it should only be used for a table of
+                /// the same build, not stored.
+                pub const fn variant_index(&self) -> usize {{
+                    *self as usize
+                }}
+
+                /// Returns the entry of this locale in `table`, a side table indexed by
+                /// [`variant_index`](Locale::variant_index) like a `[T; Locale::COUNT]`.
+                ///
+                /// Returns `None` if `table` is too short.
+                pub fn get_in<'a, T>(&self, table: &'a [T]) -> Option<&'a T> {{
+                    table.get(self.variant_index())
+                }}
+
+                /// The names of all the locales separated by commas, sorted by name, like
+                /// `"POSIX,aa_DJ,aa_ER,..."`.
+                ///
+                /// Only available with the `all-locales` feature.
+                #[doc(hidden)]
+                #[cfg(feature = "all-locales")]
+                pub const ALL_NAMES_JOINED: &'static str = {joined:?};
+
+                /// Iterate over all the locales, sorted by name.
+                pub fn variants() -> impl Iterator<Item = Locale> {{
+                    VARIANTS.iter().copied()
+                }}
+
+                /// Whether this is the default locale `POSIX`. Unlike `==` this can be used in a
+                /// `const` context.
+                pub const fn is_posix(&self) -> bool {{
+                    matches!(self, Locale::POSIX)
+                }}
+            }}
+
+            impl core::fmt::Display for Locale {{
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                    f.write_str(self.as_str())
+                }}
+            }}
+
+            impl core::fmt::Debug for Locale {{
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                    core::fmt::Display::fmt(self, f)
+                }}
+            }}
+
+            impl core::hash::Hash for Locale {{
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {{
+                    self.as_str().hash(state)
+                }}
+            }}
+
+            impl core::str::FromStr for Locale {{
+                type Err = UnknownLocale;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {{
+                    core::convert::TryFrom::<&str>::try_from(s)
+                }}
+            }}
+
+            impl core::convert::TryFrom<&str> for Locale {{
+                type Error = UnknownLocale;
+
+                fn try_from(i: &str) -> Result<Self, Self::Error> {{
+                    match i {{
+            "#,
+            joined = self.normalized_langs.keys().join(","),
+        )?;
+        f.indent(3);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
+                {lang:?} => Ok(Locale::{norm}),
+                "#,
+                lang = lang,
+                norm = norm,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => Err(UnknownLocale),
+                    }}
+                }}
+            }}
+
+            #[cfg(feature = "alloc")]
+            impl core::convert::TryFrom<alloc::string::String> for Locale {{
+                type Error = UnknownLocale;
+
+                fn try_from(i: alloc::string::String) -> Result<Self, Self::Error> {{
+                    core::convert::TryFrom::<&str>::try_from(i.as_str())
+                }}
+            }}
+
+            #[cfg(feature = "alloc")]
+            impl From<Locale> for alloc::string::String {{
+                fn from(locale: Locale) -> Self {{
+                    locale.as_str().into()
+                }}
+            }}
+
+            #[cfg(feature = "alloc")]
+            impl From<&Locale> for alloc::string::String {{
+                fn from(locale: &Locale) -> Self {{
+                    locale.as_str().into()
+                }}
+            }}
+
+            /// Serializes as the name of the locale, like `"de_DE@euro"`.
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for Locale {{
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                    serializer.serialize_str(self.as_str())
+                }}
+            }}
+
+            /// Deserializes from a name accepted by `TryFrom<&str>`, like `"de_DE@euro"`.
+            #[cfg(feature = "serde")]
+            impl<'de> serde::Deserialize<'de> for Locale {{
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                    struct LocaleVisitor;
+
+                    impl<'de> serde::de::Visitor<'de> for LocaleVisitor {{
+                        type Value = Locale;
+
+                        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{
+                            f.write_str("the name of a locale, like \"en_US\"")
+                        }}
+
+                        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Locale, E> {{
+                            core::convert::TryFrom::<&str>::try_from(v)
+                                .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                        }}
+                    }}
+
+                    deserializer.deserialize_str(LocaleVisitor)
+                }}
+            }}
+
+            /// Expands to the item `$item` of the locale `$locale`, like
+            /// `locale_match!(locale => LC_TIME::D_FMT)`.
+            ///
+            /// This is only available with the feature `all-locales`: the features of the locales are
+            /// checked in the crate that uses the macro.
+            #[cfg(feature = "all-locales")]
+            #[macro_export]
+            macro_rules! locale_match {{
+                ($locale:expr => $($item:ident)::+) => {{{{
+                    match $locale {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (_, norm) in self.normalized_langs.iter() {
+            write!(
+                f,
+                r#"
+                $crate::Locale::{norm} => $crate::locales::{norm}::$($item)::+,
+                "#,
+                norm = norm,
+            )?;
+        }
+        f.dedent(3);
+
+        write!(
+            f,
+            r#"
+                    }}
+                }}}}
+            }}
+
+            /// `locale_match!` for the locales that are enabled, to use in this crate.
+            #[allow(unused_macros)]
+            macro_rules! enabled_locale_match {{
+                ($locale:expr => $($item:ident)::+) => {{{{
+                    match $locale {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (_, norm) in self.normalized_langs.iter() {
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => crate::locales::{norm}::$($item)::+,
+                "#,
+                norm = norm,
+            )?;
+        }
+        f.dedent(3);
+
+        write!(
+            f,
+            r#"
+                    }}
+                }}}}
+            }}
+
+            "#,
+        )
+    }
+
+    fn generate_locale_impl<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+
+            impl Locale {{
+                /// Returns the locale with the same territory and the language `lang`.
+                ///
+                /// For example `fr_CA` with the language `"en"` gives `en_CA`. The modifier is not
+                /// kept. Returns `None` if this locale has no territory or if no locale exists for
+                /// this combination.
+                pub fn with_language(&self, lang: &str) -> Option<Locale> {{
+                    Locale::from_components(lang, Some(self.territory()?), None)
+                }}
+
+                /// Returns a locale with the language `lang` that uses the currency `iso4217`, like
+                /// `"EUR"`.
+                ///
+                /// If several locales match, the locale without modifier whose territory code is the
+                /// same as the language code is preferred (`de_DE` for `"de"`), and otherwise the first
+                /// by name.
+                pub fn for_currency_and_language(iso4217: &str, lang: &str) -> Option<Locale> {{
+                    match (iso4217, lang) {{
+            "#,
+        )?;
+        f.indent(3);
+
+        let mut by_currency = BTreeMap::<(&str, &str), &Lang>::new();
+        for (lang, (language, territory, modifier)) in self.components.iter() {
+            let currency = match self
+                .fields(lang, "LC_MONETARY")
+                .and_then(|x| x.get("INT_CURR_SYMBOL"))
+            {
+                Some(Value::Literal(x)) if !x.trim().is_empty() => x.trim(),
+                _ => continue,
+            };
+            let preferred = modifier.is_none()
+                && territory.as_deref() == Some(language.to_uppercase().as_str());
+            by_currency
+                .entry((currency, language))
+                .and_modify(|x| {
+                    if preferred {
+                        *x = lang;
+                    }
+                })
+                .or_insert(lang);
+        }
+        for ((currency, language), lang) in by_currency.iter() {
+            self.write_locale_cfg(&[&self.normalized_langs[*lang]], f)?;
+            write!(
+                f,
+                r#"
+                ({currency:?}, {language:?}) => Some(Locale::{norm}),
+                "#,
+                currency = currency,
+                language = language,
+                norm = self.normalized_langs[*lang],
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => None,
+                    }}
+                }}
+            "#,
+        )?;
+        f.indent(1);
+
+        let languages = self
+            .components
+            .values()
+            .filter(|(language, _, _)| language != "POSIX")
+            .map(|(language, _, _)| language)
+            .collect::<BTreeSet<_>>();
+        let territories = self
+            .components
+            .values()
+            .filter_map(|(_, territory, _)| territory.as_ref())
+            .collect::<BTreeSet<_>>();
+
+        for (name, list) in [("languages", languages), ("territories", territories)] {
+            write!(
+                f,
+                r#"
+
+                /// Iterate over the distinct {name} of all the locales, sorted.
+                pub fn iter_{name}() -> impl Iterator<Item = &'static str> {{
+                    const LIST: &[&str] = &[
+                "#,
+                name = name,
+            )?;
+            f.indent(2);
+            for x in list {
+                write!(f, "\n{:?},\n", x)?;
+            }
+            f.dedent(2);
+            write!(
+                f,
+                r#"
+                    ];
+                    LIST.iter().copied()
+                }}
+                "#,
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+
+                /// Whether `name` is the exact name of a locale, like `"en_US"` or `"de_DE@euro"`.
+                ///
+                /// This is the same check as `Locale::try_from(name)` and doesn't allocate.
+                pub fn is_valid_name(name: &str) -> bool {{
+                    <Locale as core::convert::TryFrom<&str>>::try_from(name).is_ok()
+                }}
+
+                /// Returns the canonical name of the locale matching `name`, if there is one.
+                ///
+                /// The language is lowercased, the territory uppercased, the modifier lowercased, the
+                /// codeset is dropped and `-` is accepted as separator: `"EN-us.utf8"` gives `"en_US"`
+                /// and `"de_de.UTF-8@EURO"` gives `"de_DE@euro"`. `"C"` is the same as `"POSIX"`.
+                #[cfg(feature = "alloc")]
+                pub fn normalize(name: &str) -> Option<alloc::string::String> {{
+                    use alloc::string::ToString;
+
+                    Locale::find_normalized(name).map(|x| x.to_string())
+                }}
+
+                /// Returns the locale named `name`, or else the first match after removing the codeset,
+                /// then the modifier and then the territory of `name`, like glibc does when it looks
+                /// for the files of a locale.
+                ///
+                /// `"en_US.UTF-8"` gives `en_US`, `"ca_ES@valencia"` gives `ca_ES@valencia` and
+                /// `"eo_XX"` gives `eo`. Unlike [`normalize`](Locale::normalize) the components must
+                /// be in the same case as in the locale names.
+                pub fn find_fallback(name: &str) -> Result<Locale, UnknownLocale> {{
+                    if let Ok(locale) = <Locale as core::convert::TryFrom<&str>>::try_from(name) {{
+                        return Ok(locale);
+                    }}
+                    let (language, territory, modifier) = crate::helpers::split_name(name);
+                    Locale::from_components(language, territory, modifier)
+                        .or_else(|| Locale::from_components(language, territory, None))
+                        .or_else(|| Locale::from_components(language, None, None))
+                        .ok_or(UnknownLocale)
+                }}
+
+                /// Whether `request` is the name of this locale with or without a codeset, like
+                /// `"de_DE.UTF-8@euro"` for `de_DE@euro`.
+                ///
+                /// Unlike [`find_fallback`](Locale::find_fallback) and
+                /// [`normalize`](Locale::normalize) the modifier must be the same, so `"de_DE"` is not
+                /// `de_DE@euro`, and the other components must be in the same case as in the locale
+                /// name.
+                pub fn eq_ignoring_codeset(&self, request: &str) -> bool {{
+                    let (request, modifier) = match request.find('@') {{
+                        Some(i) => request.split_at(i),
+                        None => (request, ""),
+                    }};
+                    let request = match request.find('.') {{
+                        Some(i) => &request[..i],
+                        None => request,
+                    }};
+                    let name = self.as_str();
+                    name.len() == request.len() + modifier.len()
+                        && name.starts_with(request)
+                        && name.ends_with(modifier)
+                }}
+
+                /// Returns the locale matching the value `s` of an environment variable like `LANG`,
+                /// with the same rules as [`normalize`](Locale::normalize): `"en_US.UTF-8"` gives
+                /// `en_US`.
+                ///
+                /// Returns an error if `s` is not valid UTF-8 or doesn't match any locale.
+                #[cfg(feature = "std")]
+                pub fn try_from_os_str(s: &std::ffi::OsStr) -> Result<Locale, UnknownLocale> {{
+                    s.to_str()
+                        .and_then(Locale::find_normalized)
+                        .ok_or(UnknownLocale)
+                }}
+
+                /// Iterate over the locales of a colon-separated list like the value of the `LANGUAGE`
+                /// environment variable of gettext, `"de:fr:en"`, in order.
+                ///
+                /// The entries are read like [`normalize`](Locale::normalize) does. An entry with only
+                /// a language gives the most likely locale of that language, like `en_US` for `"en"`
+                /// and `de_DE` for `"de"`. Entries that don't match a locale are skipped.
+                #[cfg(feature = "alloc")]
+                pub fn from_language_list(value: &str) -> impl Iterator<Item = Locale> + '_ {{
+                    value.split(':').filter_map(|x| {{
+                        let locale = Locale::find_normalized(x);
+                        match crate::helpers::split_name(x) {{
+                            (language, None, None) if locale.is_none() => {{
+                                Locale::likely_for_language(&language.to_ascii_lowercase())
+                            }}
+                            _ => locale,
+                        }}
+                    }})
+                }}
+
+                /// The most likely locale of the language `language`, without modifier.
+                ///
+                /// This is the locale of the likely territory of the language in CLDR, the locale
+                /// whose territory has the same code as the language, or else the first locale of
+                /// the language.
+                #[cfg(feature = "alloc")]
+                fn likely_for_language(language: &str) -> Option<Locale> {{
+                    match language {{
+            "#,
+        )?;
+        f.indent(3);
+
+        let mut likely = BTreeMap::<&str, &Lang>::new();
+        for (lang, (language, territory, modifier)) in self.components.iter() {
+            let territory = match (territory, modifier) {
+                (Some(territory), None) => territory,
+                _ => continue,
+            };
+            let preferred = match tables::lookup(tables::LIKELY_TERRITORIES, language) {
+                Some(x) => x == territory,
+                None => *territory == language.to_uppercase(),
+            };
+            likely
+                .entry(language)
+                .and_modify(|x| {
+                    if preferred {
+                        *x = lang;
+                    }
+                })
+                .or_insert(lang);
+        }
+        for (language, lang) in likely.iter() {
+            self.write_locale_cfg(&[&self.normalized_langs[*lang]], f)?;
+            write!(
+                f,
+                r#"
+                {language:?} => Some(Locale::{norm}),
+                "#,
+                language = language,
+                norm = self.normalized_langs[*lang],
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => None,
+                    }}
+                }}
+
+                #[cfg(feature = "alloc")]
+                fn find_normalized(name: &str) -> Option<Locale> {{
+                    use alloc::string::ToString;
+
+                    let (language, territory, modifier) = crate::helpers::split_name(name);
+                    let language = match language {{
+                        "C" => "POSIX".to_string(),
+                        x if x.eq_ignore_ascii_case("POSIX") => "POSIX".to_string(),
+                        x => x.to_ascii_lowercase(),
+                    }};
+                    let territory = territory.map(str::to_ascii_uppercase);
+                    let modifier = modifier.map(str::to_ascii_lowercase);
+                    Locale::from_components(&language, territory.as_deref(), modifier.as_deref())
+                }}
+
+                /// List the locales that have no value for one of the core items, the items of
+                /// [`LocaleData`], as `(locale, category, item)`.
+                ///
+                /// This is meant for maintainers, to review updates of the glibc data. The list is
+                /// empty for the current data.
+                #[cfg(feature = "alloc")]
+                pub fn report_missing_core_keys() -> alloc::vec::Vec<(Locale, &'static str, &'static str)> {{
+                    const MISSING: &[(Option<Locale>, &str, &str)] = &[
+            "#,
+        )?;
+        f.indent(3);
+
+        for lang in self.normalized_langs.keys() {
+            for (category_name, field_name) in LOCALE_DATA_FIELDS {
+                let missing = match self.fields(lang, category_name) {
+                    Some(fields) => matches!(fields.get(*field_name), None | Some(Value::Empty)),
+                    None => true,
+                };
+                if missing {
+                    write!(
+                        f,
+                        r#"
+                        (enabled::{norm}, {category:?}, {field:?}),
+                        "#,
+                        norm = self.normalized_langs[lang],
+                        category = category_name,
+                        field = field_name,
+                    )?;
+                }
+            }
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    ];
+                    MISSING
+                        .iter()
+                        .filter_map(|&(locale, category, item)| Some((locale?, category, item)))
+                        .collect()
+                }}
+
+                /// Iterate over the locales whose name matches the glob `pattern`, like `"en_*"` for all
+                /// the English locales.
+                ///
+                /// `*` matches any text, including an empty one, and `?` a single character. All the
+                /// other characters must match exactly, also `_` and `@`.
+                pub fn variants_matching(pattern: &str) -> impl Iterator<Item = Locale> + '_ {{
+                    Locale::filter_by_name(move |x| crate::helpers::glob_match(pattern, x))
+                }}
+
+                /// Returns the locale with the name closest to `name`, and its edit distance to `name`.
+                ///
+                /// The distance is the number of characters to insert, remove or replace, so a typo
+                /// like `"nl_NM"` for `nl_NL` is a distance of 1, and an exact match a distance of 0.
+                /// The comparison is case-sensitive. If several locales are equally close the
+                /// first by name is returned.
+                #[cfg(feature = "alloc")]
+                pub fn closest_by_distance(name: &str) -> Option<(Locale, usize)> {{
+                    VARIANTS
+                        .iter()
+                        .map(|x| (*x, crate::helpers::edit_distance(name, x.as_str())))
+                        .min_by_key(|&(_, distance)| distance)
+                }}
+
+                /// Iterate over the locales whose name satisfies `predicate`, like
+                /// `Locale::filter_by_name(|x| x.contains("_CH"))` for the Swiss locales.
+                pub fn filter_by_name<F>(predicate: F) -> impl Iterator<Item = Locale>
+                where
+                    F: Fn(&str) -> bool,
+                {{
+                    VARIANTS.iter().copied().filter(move |x| predicate(x.as_str()))
+                }}
+
+                /// Iterate over the locales that define the category `category`, like
+                /// `"LC_TELEPHONE"`.
+                ///
+                /// A category is defined if it is in the source of the locale in glibc, even if it is
+                /// only copied from another locale. `LC_COLLATE` and `LC_CTYPE` are supported although
+                /// their data is not available in this crate.
+                pub fn with_category(category: &str) -> impl Iterator<Item = Locale> {{
+                    let mask = match category {{
+            "#,
+        )?;
+        f.indent(3);
+
+        let all_categories = self
+            .defined_categories
+            .values()
+            .flatten()
+            .collect::<BTreeSet<_>>();
+        assert!(all_categories.len() <= 16);
+
+        for (i, category) in all_categories.iter().enumerate() {
+            write!(
+                f,
+                r#"
+                {:?} => 1 << {},
+                "#,
+                category, i,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => 0,
+                    }};
+                    VARIANTS
+                        .iter()
+                        .copied()
+                        .filter(move |x| x.defined_categories() & mask != 0)
+                }}
+
+                const fn defined_categories(&self) -> u16 {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, defined) in self.defined_categories.iter() {
+            let mask = all_categories
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| defined.contains(**x))
+                .fold(0_u16, |acc, (i, _)| acc | 1 << i);
+            self.write_locale_cfg(&[&self.normalized_langs[lang]], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {mask:#06x},
+                "#,
+                norm = self.normalized_langs[lang],
+                mask = mask,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// Returns the ISO 15924 code of the script used by this locale, like `"Latn"` or `"Cyrl"`.
+                ///
+                /// The script is derived from the modifier (`sr_RS@latin`) or otherwise from a
+                /// curated table of the default script of each language. `POSIX` has no script.
+                pub const fn script(&self) -> Option<&'static str> {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {script:?},
+                "#,
+                norm = norm,
+                script = self.script(lang),
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// Returns the title of the locale from `LC_IDENTIFICATION`, like
+                /// `"English locale for the USA"`.
+                pub const fn title(&self) -> &'static str {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, norm) in self.normalized_langs.iter() {
+            self.write_locale_cfg(&[norm.as_str()], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {title:?},
+                "#,
+                norm = norm,
+                title = self.title(lang),
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// Returns a name for the language of this locale in the language of `ui`, like
+                /// `"German"` for `de_DE` in `en_US`.
+                ///
+                /// This is a best effort: glibc has no such data and only a small table of the most
+                /// common languages is available in English, French, German and Spanish. Otherwise the
+                /// [`title`](Locale::title) of the locale is returned.
+                pub fn display_name_in(&self, ui: Locale) -> &'static str {{
+                    match (ui.language(), self.language()) {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (ui, language, name) in tables::DISPLAY_NAMES.iter() {
+            write!(
+                f,
+                r#"
+                ({ui:?}, {language:?}) => {name:?},
+                "#,
+                ui = ui,
+                language = language,
+                name = name,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => self.title(),
+                    }}
+                }}
+
+                /// Returns the qualifier of the Android resource directories for this locale, like
+                /// `"pt-rBR"` for `pt_BR` (used as `values-pt-rBR`).
+                ///
+                /// Languages with a three letter code use the BCP 47 form, like `"b+agr+PE"`. The
+                /// modifier is dropped, so `sr_RS@latin` gives `"sr-rRS"` just like `sr_RS`. `POSIX`
+                /// gives an empty string, the default resources.
+                #[cfg(feature = "alloc")]
+                pub fn to_android_qualifier(&self) -> alloc::string::String {{
+                    if self.is_posix() {{
+                        return alloc::string::String::new();
+                    }}
+                    let language = self.language();
+                    match (language.len(), self.territory()) {{
+                        (2, Some(territory)) => alloc::format!("{{}}-r{{}}", language, territory),
+                        (2, None) => language.into(),
+                        (_, Some(territory)) => alloc::format!("b+{{}}+{{}}", language, territory),
+                        (_, None) => alloc::format!("b+{{}}", language),
+                    }}
+                }}
+
+                /// Returns the locale this locale is mostly a copy of, if any.
+                ///
+                /// A locale is derived from another locale if more than half of the categories in its
+                /// source in glibc are a plain `copy` of the categories of that same locale.
+                pub const fn is_derived_from(&self) -> Option<Locale> {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, bases) in self.copies.iter() {
+            let total = self.defined_categories[lang].len();
+            let mut counts = BTreeMap::<&Lang, usize>::new();
+            for base in bases
+                .iter()
+                .filter(|x| self.normalized_langs.contains_key(*x))
+            {
+                *counts.entry(base).or_default() += 1;
+            }
+            let base = counts
+                .into_iter()
+                .find(|(_, count)| count * 2 > total)
+                .map(|(base, _)| base);
+            if let Some(base) = base {
+                self.write_locale_cfg(&[&self.normalized_langs[lang]], f)?;
+                self.write_locale_cfg(&[&self.normalized_langs[base]], f)?;
+                write!(
+                    f,
+                    r#"
+                    Locale::{norm} => Some(Locale::{base}),
+                    "#,
+                    norm = self.normalized_langs[lang],
+                    base = self.normalized_langs[base],
+                )?;
+            }
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => None,
+                    }}
+                }}
+
+                /// Format the percentage `value` with the separators of `LC_NUMERIC`, like `"50%"` for
+                /// `50.0` in `en_US` and `"12,5 %"` for `12.5` in `fr_FR`.
+                ///
+                /// glibc has no item for percentages, so where to put the `%` sign and whether it is
+                /// separated from the number by a space U+00A0 NO-BREAK SPACE is decided by a table of
+                /// languages. The default is `"50%"`.
+                #[cfg(all(feature = "alloc", feature = "lc_numeric"))]
+                pub fn format_percent(&self, value: f64) -> alloc::string::String {{
+                    let (prefix, suffix) = match self.language() {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (language, format) in tables::PERCENT_FORMATS.iter() {
+            let (prefix, suffix) = format.split_once('#').expect("format contains #");
+            write!(
+                f,
+                r#"
+                {language:?} => ({prefix:?}, {suffix:?}),
+                "#,
+                language = language,
+                prefix = prefix,
+                suffix = suffix,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => ("", "%"),
+                    }};
+                    let number = crate::helpers::format_decimal(
+                        value,
+                        enabled_locale_match!(*self => LC_NUMERIC::DECIMAL_POINT),
+                        enabled_locale_match!(*self => LC_NUMERIC::THOUSANDS_SEP),
+                        enabled_locale_match!(*self => LC_NUMERIC::typed_grouping)(),
+                    );
+                    [prefix, &number, suffix].concat()
+                }}
+
+                /// The items of the category `category` of this locale that have a value, sorted by
+                /// name, like `("CURRENCY_SYMBOL", DataValue::Str("€"))` and
+                /// `("MON_GROUPING", DataValue::IntSlice(&[3, 3]))` for `LC_MONETARY` of `de_DE`.
+                ///
+                /// The names are those of the constants of the category module. Returns an empty
+                /// iterator if `category` is not a category of this crate.
+                pub fn typed_entries(&self, category: &str) -> impl Iterator<Item = (&'static str, crate::DataValue)> {{
+                    let items: &'static [(&'static str, crate::helpers::Item)] = match category {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for category_name in self.field_metadata.keys() {
+            write!(
+                f,
+                r#"
+                #[cfg(feature = "{feature}")]
+                {name:?} => enabled_locale_match!(*self => {name}::ITEMS),
+                "#,
+                feature = category_feature(category_name),
+                name = category_name,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => &[],
+                    }};
+                    items.iter().filter_map(|&(name, item)| Some((name, item.value()?)))
+                }}
+
+                /// Write the category `category` of this locale in the syntax of the locale sources of
+                /// glibc, as read by `localedef`, like `"LC_NUMERIC\ndecimal_point \".\"\n...END LC_NUMERIC\n"`.
+                ///
+                /// Only the items that have a value are written, one per line. Strings are quoted
+                /// with `/` as escape character, and non-ASCII characters are written as `<UXXXX>`.
+                /// Returns an empty string if `category` is not a category of this crate.
+                #[cfg(feature = "alloc")]
+                pub fn to_posix_source(&self, category: &str) -> alloc::string::String {{
+                    match category {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for category_name in self.field_metadata.keys() {
+            write!(
+                f,
+                r#"
+                #[cfg(feature = "{feature}")]
+                {name:?} => crate::helpers::posix_source(category, enabled_locale_match!(*self => {name}::ITEMS)),
+                "#,
+                feature = category_feature(category_name),
+                name = category_name,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => alloc::string::String::new(),
+                    }}
+                }}
+
+                /// The language of the name of the locale, like `"de"` for `de_DE@euro` and `"ca"`
+                /// for `ca_ES@valencia`. This is `"POSIX"` for `POSIX`.
+                ///
+                /// This is usually an ISO 639 code, but some names of glibc are longer, like `"ast"`
+                /// or `"hif"`.
+                pub const fn language(&self) -> &'static str {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, (language, _, _)) in self.components.iter() {
+            self.write_locale_cfg(&[&self.normalized_langs[lang]], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {language:?},
+                "#,
+                norm = self.normalized_langs[lang],
+                language = language,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// The territory of the name of the locale, like `Some("DE")` for `de_DE@euro`, or
+                /// `None` if the name has no territory, like `eo` and `POSIX`.
+                ///
+                /// This is usually an ISO 3166 code.
+                pub const fn territory(&self) -> Option<&'static str> {{
+                    match self {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, (_, territory, _)) in self.components.iter() {
+            self.write_locale_cfg(&[&self.normalized_langs[lang]], f)?;
+            write!(
+                f,
+                r#"
+                Locale::{norm} => {territory:?},
+                "#,
+                norm = self.normalized_langs[lang],
+                territory = territory,
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                    }}
+                }}
+
+                /// The language and the territory of the name of the locale, like `("pt", Some("BR"))`
+                /// for `pt_BR`. `POSIX` has no territory and gives `("POSIX", None)`.
+                pub fn as_language_territory(&self) -> (&'static str, Option<&'static str>) {{
+                    (self.language(), self.territory())
+                }}
+
+                /// Returns the most specific locale that all of `locales` derive from, or `None` if
+                /// `locales` is empty.
+                ///
+                /// Without modifier `de_DE@euro` and `de_DE` have `de_DE` in common. Locales with the
+                /// same language have the locale of only the language in common, like `de` for
+                /// `de_DE` and `de_AT`, if it exists. Otherwise they have `POSIX` in common. glibc has
+                /// no locale for `en`, so `en_US` and `en_GB` give `POSIX`.
+                pub fn common_ancestor(locales: &[Locale]) -> Option<Locale> {{
+                    let (first, rest) = locales.split_first()?;
+                    if rest.iter().all(|x| x == first) {{
+                        return Some(*first);
+                    }}
+                    let language = first.language();
+                    let territory = first.territory();
+                    let same_language = rest.iter().all(|x| x.language() == language);
+                    let same_territory = same_language && rest.iter().all(|x| x.territory() == territory);
+                    let ancestor = match (same_language, same_territory) {{
+                        (true, true) => Locale::from_components(language, territory, None)
+                            .or_else(|| Locale::from_components(language, None, None)),
+                        (true, false) => Locale::from_components(language, None, None),
+                        _ => None,
+                    }};
+                    Some(ancestor.unwrap_or(Locale::POSIX))
+                }}
+
+                /// Returns the locale with the name `language[_territory][@modifier]`, like `de_DE@euro`
+                /// for `("de", Some("DE"), Some("euro"))`.
+                ///
+                /// The components must be in the same case as in the locale names.
+                pub fn from_components(
+                    language: &str,
+                    territory: Option<&str>,
+                    modifier: Option<&str>,
+                ) -> Option<Locale> {{
+                    match (language, territory, modifier) {{
+            "#,
+        )?;
+        f.indent(3);
+
+        for (lang, components) in self.components.iter() {
+            self.write_locale_cfg(&[&self.normalized_langs[lang]], f)?;
+            write!(
+                f,
+                r#"
+                {components:?} => Some(Locale::{norm}),
+                "#,
+                components = components,
+                norm = self.normalized_langs[lang],
+            )?;
+        }
+
+        f.dedent(3);
+        write!(
+            f,
+            r#"
+                        _ => None,
+                    }}
+                }}
+            }}
+            "#,
+        )
+    }
+
+    /// Private functions shared by the functions of the category modules.
+    fn generate_helpers<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+            mod helpers {{
+                /// Rotate the 7 days of `days` (starting on Sunday) so they start on `first_weekday`
+                /// (1 is Sunday). Without a valid `first_weekday` the week starts on Sunday.
+                pub(crate) const fn rotate_weekdays(
+                    days: &[&'static str],
+                    first_weekday: Option<i64>,
+                ) -> [&'static str; 7] {{
+                    let first = match first_weekday {{
+                        Some(x) if x >= 1 && x <= 7 => (x - 1) as usize,
+                        _ => 0,
+                    }};
+                    let mut result = [""; 7];
+                    let mut i = 0;
+                    while i < 7 {{
+                        result[i] = days[(first + i) % 7];
+                        i += 1;
+                    }}
+                    result
+                }}
+
+                /// `FRAC_DIGITS` or `INT_FRAC_DIGITS`, falling back to the ISO 4217 minor units of the
+                /// currency if it is `-1` (`CHAR_MAX`).
+                pub(crate) fn effective_frac_digits(frac_digits: i64, int_curr_symbol: &str) -> u8 {{
+                    match frac_digits {{
+                        0..=126 => frac_digits as u8,
+                        _ => int_curr_symbol
+                            .get(..3)
+                            .and_then(crate::iso4217_minor_units)
+                            .unwrap_or(2),
+                    }}
+                }}
+
+                /// Split a locale name like `en_US.UTF-8@euro` into its language, territory and
+                /// modifier. The codeset is dropped and `-` is accepted instead of `_`.
+                pub(crate) fn split_name(name: &str) -> (&str, Option<&str>, Option<&str>) {{
+                    let (name, modifier) = match name.find('@') {{
+                        Some(i) => (&name[..i], Some(&name[i + 1..])),
+                        None => (name, None),
+                    }};
+                    let name = match name.find('.') {{
+                        Some(i) => &name[..i],
+                        None => name,
+                    }};
+                    match name.find(|c| c == '_' || c == '-') {{
+                        Some(i) => (&name[..i], Some(&name[i + 1..]), modifier),
+                        None => (name, None, modifier),
+                    }}
+                }}
+
+                /// The day of the week of a date written as `YYYYMMDD` like in `WEEK` (0 is Sunday).
+                const fn weekday(date: i64) -> i64 {{
+                    const OFFSETS: [i64; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+                    let (mut year, month, day) = (date / 10000, (date / 100) % 100, date % 100);
+                    if month < 3 {{
+                        year -= 1;
+                    }}
+                    let month_offset = match month {{
+                        1..=12 => OFFSETS[month as usize - 1],
+                        _ => 0,
+                    }};
+                    (year + year / 4 - year / 100 + year / 400 + month_offset + day).rem_euclid(7)
+                }}
+
+                /// The first day of the week described by `WEEK` and `FIRST_WEEKDAY` (0 is Sunday).
+                ///
+                /// `FIRST_WEEKDAY` counts from the day of the date in `WEEK`, which defaults to
+                /// 1997-11-30, a Sunday.
+                pub(crate) const fn first_weekday(week: Option<&[i64]>, first_weekday: Option<i64>) -> u8 {{
+                    let first_day = match week {{
+                        Some(&[_, first_day, ..]) => first_day,
+                        _ => 19971130,
+                    }};
+                    let first_weekday = match first_weekday {{
+                        Some(x) => x,
+                        None => 1,
+                    }};
+                    (weekday(first_day) + first_weekday - 1).rem_euclid(7) as u8
+                }}
+
+                /// Whether `WEEK` and `FIRST_WEEKDAY` describe ISO 8601 week numbering.
+                pub(crate) const fn uses_iso8601_weeks(week: Option<&[i64]>, first_weekday: Option<i64>) -> bool {{
+                    match week {{
+                        Some(&[_, _, 4]) => self::first_weekday(week, first_weekday) == 1,
+                        _ => false,
+                    }}
+                }}
+
+                /// The locales of `locales` that are enabled. `N` must be their number.
+                pub(crate) const fn enabled<const N: usize>(locales: &[Option<crate::Locale>]) -> [crate::Locale; N] {{
+                    let mut result = [crate::Locale::POSIX; N];
+                    let (mut i, mut j) = (0, 0);
+                    while i < locales.len() {{
+                        if let Some(locale) = locales[i] {{
+                            result[j] = locale;
+                            j += 1;
+                        }}
+                        i += 1;
+                    }}
+                    result
+                }}
+
+                /// The number of locales of `locales` that are enabled.
+                pub(crate) const fn count_enabled(locales: &[Option<crate::Locale>]) -> usize {{
+                    let (mut i, mut count) = (0, 0);
+                    while i < locales.len() {{
+                        if locales[i].is_some() {{
+                            count += 1;
+                        }}
+                        i += 1;
+                    }}
+                    count
+                }}
+
+                /// The month `n` (1 is January) of a list of 12 month names.
+                pub(crate) const fn month(months: &[&'static str], n: u8) -> Option<&'static str> {{
+                    if n >= 1 && n as usize <= months.len() {{
+                        Some(months[n as usize - 1])
+                    }} else {{
+                        None
+                    }}
+                }}
+
+                /// `name` with its first character in uppercase, like `"Janvier"` for `"janvier"`.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn titlecase(name: &str) -> alloc::string::String {{
+                    let mut chars = name.chars();
+                    match chars.next() {{
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => alloc::string::String::new(),
+                    }}
+                }}
+
+                /// The Levenshtein distance between `a` and `b`: the number of characters to insert,
+                /// remove or replace to turn one into the other.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn edit_distance(a: &str, b: &str) -> usize {{
+                    let b: alloc::vec::Vec<char> = b.chars().collect();
+                    // the distances between the part of `a` seen so far and every prefix of `b`
+                    let mut row: alloc::vec::Vec<usize> = (0..=b.len()).collect();
+                    for (i, x) in a.chars().enumerate() {{
+                        let mut diagonal = row[0];
+                        row[0] = i + 1;
+                        for (j, &y) in b.iter().enumerate() {{
+                            let replace = diagonal + (x != y) as usize;
+                            diagonal = row[j + 1];
+                            row[j + 1] = replace.min(row[j] + 1).min(diagonal + 1);
+                        }}
+                    }}
+                    row[b.len()]
+                }}
+
+                /// Whether `name` matches the glob `pattern`, where `*` matches any text and `?` any
+                /// single character.
+                pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {{
+                    let (pattern, name) = (pattern.as_bytes(), name.as_bytes());
+                    let (mut p, mut n) = (0, 0);
+                    // position of the last `*` in `pattern`, and of `name` when it was reached
+                    let mut backtrack = None;
+                    while n < name.len() {{
+                        match pattern.get(p) {{
+                            Some(b'*') => {{
+                                backtrack = Some((p, n));
+                                p += 1;
+                            }}
+                            Some(&x) if x == b'?' || x == name[n] => {{
+                                p += 1;
+                                n += 1;
+                            }}
+                            _ => match backtrack {{
+                                Some((star, matched)) => {{
+                                    backtrack = Some((star, matched + 1));
+                                    p = star + 1;
+                                    n = matched + 1;
+                                }}
+                                None => return false,
+                            }},
+                        }}
+                    }}
+                    pattern[p..].iter().all(|&x| x == b'*')
+                }}
+
+                /// The [`NegativeDisplay`](crate::NegativeDisplay) of `NEGATIVE_SIGN`, `N_SIGN_POSN` and
+                /// `N_CS_PRECEDES`.
+                pub(crate) const fn negative_display(
+                    negative_sign: &str,
+                    sign_posn: i64,
+                    cs_precedes: i64,
+                ) -> crate::NegativeDisplay {{
+                    use crate::{{CsPrecedes, NegativeDisplay, SignPosition}};
+
+                    let sign_posn = SignPosition::from_i64(sign_posn);
+                    if matches!(sign_posn, Some(SignPosition::Parentheses)) {{
+                        return NegativeDisplay::Parentheses;
+                    }}
+                    if negative_sign.is_empty() {{
+                        return NegativeDisplay::Custom;
+                    }}
+                    match (sign_posn, CsPrecedes::from_i64(cs_precedes)) {{
+                        (Some(SignPosition::BeforeAll), _)
+                        | (Some(SignPosition::BeforeSymbol), Some(CsPrecedes::Precedes)) => {{
+                            NegativeDisplay::LeadingMinus
+                        }}
+                        (Some(SignPosition::AfterAll), _)
+                        | (Some(SignPosition::AfterSymbol), Some(CsPrecedes::Follows)) => {{
+                            NegativeDisplay::TrailingMinus
+                        }}
+                        (Some(SignPosition::AfterSymbol), Some(CsPrecedes::Precedes)) => {{
+                            NegativeDisplay::LeadingSymbolSign
+                        }}
+                        (Some(SignPosition::BeforeSymbol), Some(CsPrecedes::Follows)) => {{
+                            NegativeDisplay::TrailingSymbolSign
+                        }}
+                        _ => NegativeDisplay::Custom,
+                    }}
+                }}
+
+                /// Write the integer part `digits` to `out`, with `thousands_sep` between the groups
+                /// counted from the decimal point.
+                pub(crate) fn write_grouped<W: core::fmt::Write + ?Sized>(
+                    out: &mut W,
+                    digits: &str,
+                    thousands_sep: &str,
+                    mut sizes: crate::GroupSizes,
+                ) -> core::fmt::Result {{
+                    let end = digits.len();
+                    let start = match sizes.next() {{
+                        Some(size) => end.saturating_sub(size),
+                        None => 0,
+                    }};
+                    if start > 0 {{
+                        // the groups further from the decimal point are written first
+                        write_grouped(out, &digits[..start], thousands_sep, sizes)?;
+                        out.write_str(thousands_sep)?;
+                    }}
+                    out.write_str(&digits[start..end])
+                }}
+
+                /// Join the groups of the integer part `digits` with `thousands_sep`, starting from the
+                /// decimal point.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn group_digits(digits: &str, thousands_sep: &str, grouping: &'static [crate::GroupSize]) -> alloc::string::String {{
+                    let mut result = alloc::string::String::new();
+                    write_grouped(&mut result, digits, thousands_sep, crate::GroupSizes::new(grouping))
+                        .expect("writing to a String never fails");
+                    result
+                }}
+
+                /// Format `value` with the decimal point and the grouping of `LC_NUMERIC`, with as
+                /// many digits after the decimal point as needed.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn format_decimal(
+                    value: f64,
+                    decimal_point: &str,
+                    thousands_sep: &str,
+                    grouping: &'static [crate::GroupSize],
+                ) -> alloc::string::String {{
+                    let value = alloc::format!("{{}}", value);
+                    let (sign, value) = match value.strip_prefix('-') {{
+                        Some(x) => ("-", x),
+                        None => ("", value.as_str()),
+                    }};
+                    let (integer, fraction) = match value.find('.') {{
+                        Some(i) => (&value[..i], Some(&value[i + 1..])),
+                        None => (value, None),
+                    }};
+                    if !integer.bytes().all(|x| x.is_ascii_digit()) {{
+                        // `inf` or `NaN`
+                        return [sign, value].concat();
+                    }}
+                    let mut result = alloc::string::String::from(sign);
+                    result.push_str(&group_digits(integer, thousands_sep, grouping));
+                    if let Some(fraction) = fraction {{
+                        result.push_str(decimal_point);
+                        result.push_str(fraction);
+                    }}
+                    result
+                }}
+
+                /// Replace `%Z` in `format` by `abbreviation` and `%z` by `offset`, or by nothing if they
+                /// are `None`.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn substitute_timezone(
+                    format: &'static str,
+                    abbreviation: Option<&str>,
+                    offset: Option<&str>,
+                ) -> alloc::string::String {{
+                    let mut result = alloc::string::String::new();
+                    for token in crate::FormatTokens::new(format) {{
+                        match token {{
+                            crate::FormatToken::Specifier("%Z") => result.push_str(abbreviation.unwrap_or("")),
+                            crate::FormatToken::Specifier("%z") => result.push_str(offset.unwrap_or("")),
+                            crate::FormatToken::Literal(x) | crate::FormatToken::Specifier(x) => result.push_str(x),
+                        }}
+                    }}
+                    result
+                }}
+
+                /// Fill in a telephone number format like `TEL_DOM_FMT`: `%a` is replaced by `area`,
+                /// `%A` by `area` with the national prefix `0`, `%l` by `number`, and `%t` by a space
+                /// if the previous field was not empty.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn format_phone_number(
+                    format: &'static str,
+                    area: &str,
+                    number: &str,
+                ) -> alloc::string::String {{
+                    let mut result = alloc::string::String::new();
+                    let mut previous_empty = true;
+                    for token in crate::FormatTokens::new(format) {{
+                        match token {{
+                            crate::FormatToken::Specifier("%a") => {{
+                                result.push_str(area);
+                                previous_empty = area.is_empty();
+                            }}
+                            crate::FormatToken::Specifier("%A") => {{
+                                if !area.is_empty() {{
+                                    result.push('0');
+                                    result.push_str(area);
+                                }}
+                                previous_empty = area.is_empty();
+                            }}
+                            crate::FormatToken::Specifier("%l") => {{
+                                result.push_str(number);
+                                previous_empty = number.is_empty();
+                            }}
+                            crate::FormatToken::Specifier("%t") => {{
+                                if !previous_empty {{
+                                    result.push(' ');
+                                }}
+                            }}
+                            crate::FormatToken::Specifier("%%") => result.push('%'),
+                            crate::FormatToken::Literal(x) | crate::FormatToken::Specifier(x) => result.push_str(x),
+                        }}
+                    }}
+                    result
+                }}
+
+                /// The value of an item of a category, for [`posix_source`] and
+                /// [`Locale::typed_entries`](crate::Locale::typed_entries).
+                #[derive(Copy, Clone)]
+                pub(crate) enum Item {{
+                    Str(Option<&'static str>),
+                    Int(Option<i64>),
+                    StrSlice(Option<&'static [&'static str]>),
+                    IntSlice(Option<&'static [i64]>),
+                    StrSlice2d(Option<&'static [&'static [&'static str]]>),
+                    // not used by the current data
+                    #[allow(dead_code)]
+                    IntSlice2d(Option<&'static [&'static [i64]]>),
+                }}
+
+                impl Item {{
+                    pub(crate) fn value(self) -> Option<crate::DataValue> {{
+                        use crate::DataValue;
+                        match self {{
+                            Item::Str(x) => x.map(DataValue::Str),
+                            Item::Int(x) => x.map(DataValue::Int),
+                            Item::StrSlice(x) => x.map(DataValue::StrSlice),
+                            Item::IntSlice(x) => x.map(DataValue::IntSlice),
+                            Item::StrSlice2d(x) => x.map(DataValue::StrSlice2d),
+                            Item::IntSlice2d(x) => x.map(DataValue::IntSlice2d),
+                        }}
+                    }}
+                }}
+
+                /// Write the category `category` with the items `items` in the syntax of the locale
+                /// sources of glibc, with `/` as escape character.
+                #[cfg(feature = "alloc")]
+                pub(crate) fn posix_source(category: &str, items: &[(&str, Item)]) -> alloc::string::String {{
+                    use alloc::string::{{String, ToString}};
+                    use core::fmt::Write;
+
+                    fn push_str(out: &mut String, value: &str) {{
+                        out.push('"');
+                        for c in value.chars() {{
+                            match c {{
+                                '"' | '/' | '<' | '>' => {{
+                                    out.push('/');
+                                    out.push(c);
+                                }}
+                                ' '..='~' => out.push(c),
+                                c if (c as u32) < 0x10000 => {{
+                                    let _ = write!(out, "<U{{:04X}}>", c as u32);
+                                }}
+                                c => {{
+                                    let _ = write!(out, "<U{{:08X}}>", c as u32);
+                                }}
+                            }}
+                        }}
+                        out.push('"');
+                    }}
+
+                    fn push_line<T: Copy>(out: &mut String, key: &str, values: &[T], push: fn(&mut String, T)) {{
+                        out.push_str(&key.to_ascii_lowercase());
+                        for (i, value) in values.iter().enumerate() {{
+                            out.push(if i == 0 {{ ' ' }} else {{ ';' }});
+                            push(out, *value);
+                        }}
+                        out.push('\n');
+                    }}
+
+                    let push_int = |out: &mut String, value: i64| out.push_str(&value.to_string());
+
+                    let mut out = String::new();
+                    out.push_str(category);
+                    out.push('\n');
+                    for &(key, item) in items {{
+                        match item {{
+                            Item::Str(Some(x)) => push_line(&mut out, key, &[x], push_str),
+                            Item::Int(Some(x)) => push_line(&mut out, key, &[x], push_int),
+                            Item::StrSlice(Some(x)) if !x.is_empty() => push_line(&mut out, key, x, push_str),
+                            Item::IntSlice(Some(x)) => push_line(&mut out, key, x, push_int),
+                            Item::StrSlice2d(Some(rows)) => {{
+                                for row in rows {{
+                                    push_line(&mut out, key, row, push_str);
+                                }}
+                            }}
+                            Item::IntSlice2d(Some(rows)) => {{
+                                for row in rows {{
+                                    push_line(&mut out, key, row, push_int);
+                                }}
+                            }}
+                            _ => {{}}
+                        }}
+                    }}
+                    out.push_str("END ");
+                    out.push_str(category);
+                    out.push('\n');
+                    out
+                }}
+
+                /// The rules of `LC_MONETARY` to format an amount of money with one sign.
+                #[cfg(feature = "alloc")]
+                pub(crate) struct MonetaryFormat {{
+                    pub(crate) currency_symbol: &'static str,
+                    pub(crate) decimal_point: &'static str,
+                    pub(crate) thousands_sep: &'static str,
+                    pub(crate) grouping: &'static [crate::GroupSize],
+                    pub(crate) frac_digits: u8,
+                    pub(crate) sign: &'static str,
+                    pub(crate) cs_precedes: i64,
+                    pub(crate) sep_by_space: i64,
+                    pub(crate) sign_posn: i64,
+                }}
+
+                #[cfg(feature = "alloc")]
+                impl MonetaryFormat {{
+                    /// Format an amount given in hundredths, rounded to `frac_digits`.
+                    pub(crate) fn format(&self, hundredths: u64) -> alloc::string::String {{
+                        use alloc::string::{{String, ToString}};
+
+                        let frac_digits = self.frac_digits.min(18) as u32;
+                        let scaled = if frac_digits >= 2 {{
+                            hundredths * 10u64.pow(frac_digits - 2)
+                        }} else {{
+                            let divisor = 10u64.pow(2 - frac_digits);
+                            (hundredths + divisor / 2) / divisor
+                        }};
+                        let unit = 10u64.pow(frac_digits);
+
+                        let mut value = group_digits(&(scaled / unit).to_string(), self.thousands_sep, self.grouping);
+                        if frac_digits > 0 {{
+                            value.push_str(self.decimal_point);
+                            let frac = (scaled % unit).to_string();
+                            for _ in frac.len()..frac_digits as usize {{
+                                value.push('0');
+                            }}
+                            value.push_str(&frac);
+                        }}
+
+                        // `SEP_BY_SPACE` 1 separates the symbol (with an adjacent sign) from the value, 2
+                        // separates the sign from the symbol if adjacent or else from the value.
+                        let (space1, space2) = match self.sep_by_space {{
+                            1 => (true, false),
+                            2 => (false, true),
+                            _ => (false, false),
+                        }};
+                        let symbol = self.currency_symbol;
+                        let sign = self.sign;
+                        let parts: [(&str, bool); 3] = match (self.cs_precedes != 0, self.sign_posn) {{
+                            (true, 0) => [(symbol, false), (&value, space1), ("", false)],
+                            (true, 2) => [(symbol, false), (&value, space1), (sign, space2)],
+                            (true, 4) => [(symbol, false), (sign, space2), (&value, space1)],
+                            (true, _) => [(sign, false), (symbol, space2), (&value, space1)],
+                            (false, 0) => [(&value, false), (symbol, space1), ("", false)],
+                            (false, 2) | (false, 4) => [(&value, false), (symbol, space1), (sign, space2)],
+                            (false, 3) => [(&value, false), (sign, space1), (symbol, space2)],
+                            (false, _) => [(sign, false), (&value, space2), (symbol, space1)],
+                        }};
+                        let mut result = String::new();
+                        for (part, space_before) in parts.iter() {{
+                            if part.is_empty() {{
+                                continue;
+                            }}
+                            if *space_before && !result.is_empty() {{
+                                result.push(' ');
+                            }}
+                            result.push_str(part);
+                        }}
+                        if self.sign_posn == 0 {{
+                            result = ["(", &result, ")"].concat();
+                        }}
+                        result
+                    }}
+                }}
+            }}
+
+            "#,
+        )
+    }
+
+    /// Slices that are the value of several items, like the `AM_PM` of many locales. The items
+    /// refer to these constants to share a single copy of the slice.
+    ///
+    /// Returns the index of every shared slice by its type and literal.
+    fn generate_slices<W: Write>(
+        &self,
+        f: &mut CodeFormatter<W>,
+    ) -> Result<HashMap<(String, String), usize>, std::fmt::Error> {
+        let mut counts = HashMap::<(String, String), usize>::new();
+        let mut order = Vec::new();
+        for categories in self.by_language.values() {
+            for (category_name, category) in categories.iter() {
+                let fields = match category {
+                    Category::Fields(fields) => fields,
+                    Category::Link(_) => continue,
+                };
+                for (field_name, meta) in self.field_metadata[category_name].iter() {
+                    let key = match fields[field_name].with_fixed_type(meta).slice_key(meta) {
+                        Some(key) => key,
+                        None => continue,
+                    };
+                    let count = counts.entry(key.clone()).or_default();
+                    if *count == 0 {
+                        order.push(key);
+                    }
+                    *count += 1;
+                }
+            }
+        }
+
+        write!(
+            f,
+            r#"
+
+            /// The values of the items that several locales have in common, like the `GROUPING`
+            /// `[3, 3]`, so `lib.rs` contains them once.
+            ///
+            /// These are `const`s because the public `const`s of the locales refer to them, and a
+            /// `const` can't refer to a `static` before Rust 1.83 (the MSRV is 1.56). The locales
+            /// share the value, but the language doesn't guarantee that they share its address:
+            /// each use of a `const` may get its own copy, even though rustc places the value of a
+            /// `const` once in practice.
+            mod slices {{
+            "#,
+        )?;
+        f.indent(1);
+
+        let mut slices = HashMap::new();
+        for key in order.into_iter().filter(|x| counts[x] > 1) {
+            // NOTE: the literal of a 2D array is written for this indentation
+            write!(
+                f,
+                r#"
+            pub(crate) const SLICE_{i}: {ty} = {val};
+            "#,
+                i = slices.len(),
+                ty = key.0,
+                val = key.1,
+            )?;
+            let i = slices.len();
+            slices.insert(key, i);
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
+            "#,
+        )?;
+
+        Ok(slices)
+    }
+
+    /// Public types used by the functions of the category modules.
+    fn generate_types<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"
+            /// Iterator over the sizes of the digit groups of a [`Grouping`], starting from the
+            /// decimal point.
+            ///
+            /// The last size is repeated indefinitely, unless the grouping ends with
+            /// [`GroupSize::NoMore`] after which no further grouping is done.
+            #[derive(Copy, Clone, Debug)]
+            pub struct GroupSizes {{
+                sizes: &'static [GroupSize],
+                index: usize,
+            }}
+
+            impl GroupSizes {{
+                pub const fn new(sizes: &'static [GroupSize]) -> Self {{
+                    Self {{ sizes, index: 0 }}
+                }}
+            }}
+
+            impl Iterator for GroupSizes {{
+                type Item = usize;
+
+                fn next(&mut self) -> Option<usize> {{
+                    let size = match self.sizes.get(self.index) {{
+                        Some(x) => x,
+                        None => self.sizes.last()?,
+                    }};
+                    match *size {{
+                        GroupSize::Repeat(x) => {{
+                            self.index = self.index.saturating_add(1).min(self.sizes.len());
+                            Some(x as usize)
+                        }}
+                        GroupSize::NoMore => None,
+                    }}
+                }}
+            }}
+
+            /// The size of a digit group in [`LC_NUMERIC::typed_grouping`] or
+            /// [`LC_MONETARY::typed_mon_grouping`], starting from the decimal point.
+            ///
+            /// [`LC_NUMERIC::typed_grouping`]: locales::POSIX::LC_NUMERIC::typed_grouping
+            /// [`LC_MONETARY::typed_mon_grouping`]: locales::POSIX::LC_MONETARY::typed_mon_grouping
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum GroupSize {{
+                /// A group of this number of digits. The last size is repeated for the remaining
+                /// digits.
+                Repeat(u8),
+                /// No further grouping is done.
+                NoMore,
+            }}
+
+            /// A `GROUPING` or `MON_GROUPING` decoded into [`GroupSize`]s, see
+            /// [`LC_NUMERIC::grouping`] and [`LC_MONETARY::mon_grouping`].
+            ///
+            /// glibc stores the sizes of the digit groups starting from the decimal point. The last
+            /// size is repeated for the remaining digits, unless the grouping ends with `-1`
+            /// (`CHAR_MAX`) after which no further grouping is done. A `0` repeats the previous size,
+            /// and a grouping starting with `0` or `-1` means no grouping at all. These rules are
+            /// applied when the locales are generated, with the same result as
+            /// [`LC_NUMERIC::typed_grouping`].
+            ///
+            /// [`LC_NUMERIC::grouping`]: locales::POSIX::LC_NUMERIC::grouping
+            /// [`LC_MONETARY::mon_grouping`]: locales::POSIX::LC_MONETARY::mon_grouping
+            /// [`LC_NUMERIC::typed_grouping`]: locales::POSIX::LC_NUMERIC::typed_grouping
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct Grouping(&'static [GroupSize]);
+
+            impl Grouping {{
+                pub const fn new(sizes: &'static [GroupSize]) -> Self {{
+                    Self(sizes)
+                }}
+
+                /// The decoded sizes, like `[Repeat(3), Repeat(2)]` for `GROUPING` of `en_IN`.
+                pub const fn as_slice(&self) -> &'static [GroupSize] {{
+                    self.0
+                }}
+
+                /// The sizes of the digit groups, starting from the decimal point.
+                pub const fn group_sizes(&self) -> GroupSizes {{
+                    GroupSizes::new(self.0)
+                }}
+
+                /// Whether digits are grouped at all.
+                pub const fn is_grouped(&self) -> bool {{
+                    !self.0.is_empty() && matches!(self.0[0], GroupSize::Repeat(_))
+                }}
+
+                /// Whether the last size is repeated for the remaining digits, like for `[3]` of
+                /// `en_US`. This is `false` if the grouping ends with `-1`, or if there is no grouping
+                /// at all.
+                pub const fn repeats_last(&self) -> bool {{
+                    !self.0.is_empty() && matches!(self.0[self.0.len() - 1], GroupSize::Repeat(_))
+                }}
+            }}
+
+            /// The kind of digit grouping of a locale, see [`LC_NUMERIC::grouping_style`].
+            ///
+            /// [`LC_NUMERIC::grouping_style`]: locales::POSIX::LC_NUMERIC::grouping_style
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum GroupingStyle {{
+                /// Groups of 3 digits, like `"1,234,567"`.
+                Western,
+                /// A group of 3 digits followed by groups of 2, like `"12,34,567"`.
+                Indian,
+                /// No grouping at all.
+                None,
+                /// Any other grouping.
+                Custom,
+            }}
+
+            /// Where the sign of an amount of money goes, see [`LC_MONETARY::p_sign_posn`] and
+            /// [`LC_MONETARY::n_sign_posn`]. The discriminant is the value of `P_SIGN_POSN` or
+            /// `N_SIGN_POSN`.
+            ///
+            /// [`LC_MONETARY::p_sign_posn`]: locales::POSIX::LC_MONETARY::p_sign_posn
+            /// [`LC_MONETARY::n_sign_posn`]: locales::POSIX::LC_MONETARY::n_sign_posn
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum SignPosition {{
+                /// Parentheses around the amount and the currency symbol, like `"($1.00)"`.
+                Parentheses = 0,
+                /// The sign before the amount and the currency symbol, like `"-$1.00"`.
+                BeforeAll = 1,
+                /// The sign after the amount and the currency symbol, like `"$1.00-"`.
+                AfterAll = 2,
+                /// The sign right before the currency symbol, like `"-$1.00"` or `"1.00-$"`.
+                BeforeSymbol = 3,
+                /// The sign right after the currency symbol, like `"$-1.00"` or `"1.00$-"`.
+                AfterSymbol = 4,
+            }}
+
+            impl SignPosition {{
+                /// The position for a value of `P_SIGN_POSN` or `N_SIGN_POSN`, or `None` if it is
+                /// not specified (`-1`, `CHAR_MAX`).
+                pub const fn from_i64(value: i64) -> Option<Self> {{
+                    match value {{
+                        0 => Some(SignPosition::Parentheses),
+                        1 => Some(SignPosition::BeforeAll),
+                        2 => Some(SignPosition::AfterAll),
+                        3 => Some(SignPosition::BeforeSymbol),
+                        4 => Some(SignPosition::AfterSymbol),
+                        _ => None,
+                    }}
+                }}
+            }}
+
+            /// Whether the currency symbol goes before or after an amount of money, see
+            /// [`LC_MONETARY::p_cs_precedes`] and [`LC_MONETARY::n_cs_precedes`]. The discriminant is
+            /// the value of `P_CS_PRECEDES` or `N_CS_PRECEDES`.
+            ///
+            /// [`LC_MONETARY::p_cs_precedes`]: locales::POSIX::LC_MONETARY::p_cs_precedes
+            /// [`LC_MONETARY::n_cs_precedes`]: locales::POSIX::LC_MONETARY::n_cs_precedes
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum CsPrecedes {{
+                /// The currency symbol follows the amount, like `"1,00 €"`.
+                Follows = 0,
+                /// The currency symbol precedes the amount, like `"$1.00"`.
+                Precedes = 1,
+            }}
+
+            impl CsPrecedes {{
+                /// The order for a value of `P_CS_PRECEDES` or `N_CS_PRECEDES`, or `None` if it is
+                /// not specified (`-1`, `CHAR_MAX`).
+                pub const fn from_i64(value: i64) -> Option<Self> {{
+                    match value {{
+                        0 => Some(CsPrecedes::Follows),
+                        1 => Some(CsPrecedes::Precedes),
+                        _ => None,
+                    }}
+                }}
+            }}
+
+            /// How a negative amount of money is written, see [`LC_MONETARY::negative_display`].
+            ///
+            /// This sums up `NEGATIVE_SIGN`, `N_SIGN_POSN` and `N_CS_PRECEDES`. The examples use `-`
+            /// as sign, but the sign is the `NEGATIVE_SIGN` of the locale.
+            ///
+            /// [`LC_MONETARY::negative_display`]: locales::POSIX::LC_MONETARY::negative_display
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum NegativeDisplay {{
+                /// The sign comes first, like `"-$1.00"` or `"-1,00 €"`.
+                LeadingMinus,
+                /// The sign comes last, like `"$1.00-"` or `"1,00 €-"`.
+                TrailingMinus,
+                /// Parentheses around the amount and the currency symbol, like `"($1.00)"`.
+                Parentheses,
+                /// The sign between a leading currency symbol and the amount, like `"$-1.00"`.
+                LeadingSymbolSign,
+                /// The sign between the amount and a trailing currency symbol, like `"1,00 -€"`.
+                TrailingSymbolSign,
+                /// Any other combination, like a missing `NEGATIVE_SIGN` or positions that are not
+                /// specified.
+                Custom,
+            }}
+
+            /// Returns the number of digits after the decimal point of an ISO 4217 currency code, like
+            /// `0` for `"JPY"` and `2` for `"USD"`.
+            ///
+            /// Only the currencies that don't use 2 digits are listed in a table, all other codes are
+            /// assumed to use 2 digits. Returns `None` if `code` is not made of 3 uppercase ASCII
+            /// letters.
+            pub fn iso4217_minor_units(code: &str) -> Option<u8> {{
+                if code.len() != 3 || !code.bytes().all(|x| x.is_ascii_uppercase()) {{
+                    return None;
+                }}
+                Some(match code {{
+                    "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+                    | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+                    "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+                    "CLF" | "UYW" => 4,
+                    _ => 2,
+                }})
+            }}
+
+            /// A part of a `strftime`-like format string, see [`DateFormat::tokens`].
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum FormatToken {{
+                /// Text that is copied as is.
+                Literal(&'static str),
+                /// A conversion specification including the `%`, its flags, width and modifier,
+                /// like `"%d"`, `"%-m"` or `"%Ey"`.
+                Specifier(&'static str),
+            }}
+
+            /// Iterator over the [`FormatToken`]s of a format string.
+            #[derive(Copy, Clone, Debug)]
+            pub struct FormatTokens {{
+                rest: &'static str,
+            }}
+
+            impl FormatTokens {{
+                pub const fn new(format: &'static str) -> Self {{
+                    Self {{ rest: format }}
+                }}
+            }}
+
+            impl Iterator for FormatTokens {{
+                type Item = FormatToken;
+
+                fn next(&mut self) -> Option<FormatToken> {{
+                    let bytes = self.rest.as_bytes();
+                    if bytes.is_empty() {{
+                        return None;
+                    }}
+                    if bytes[0] != b'%' {{
+                        let len = self.rest.find('%').unwrap_or(self.rest.len());
+                        let (token, rest) = self.rest.split_at(len);
+                        self.rest = rest;
+                        return Some(FormatToken::Literal(token));
+                    }}
+                    let mut len = 1;
+                    while len < bytes.len() && matches!(bytes[len], b'-' | b'_' | b'0' | b'^' | b'#') {{
+                        len += 1;
+                    }}
+                    while len < bytes.len() && bytes[len].is_ascii_digit() {{
+                        len += 1;
+                    }}
+                    if len < bytes.len() && matches!(bytes[len], b'E' | b'O') {{
+                        len += 1;
+                    }}
+                    len += self.rest[len..].chars().next().map_or(0, char::len_utf8);
+                    let (token, rest) = self.rest.split_at(len);
+                    self.rest = rest;
+                    Some(FormatToken::Specifier(token))
+                }}
+            }}
+
+            /// A day of the week.
+            ///
+            /// The days are ordered like the day names of `LC_TIME` in glibc, starting on Sunday:
+            /// `Sunday < Monday < ... < Saturday`. The discriminant is the index in `DAY` and
+            /// `ABDAY`.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum Weekday {{
+                Sunday = 0,
+                Monday = 1,
+                Tuesday = 2,
+                Wednesday = 3,
+                Thursday = 4,
+                Friday = 5,
+                Saturday = 6,
+            }}
+
+            /// A month of the year, ordered from `January` to `December`.
+            ///
+            /// The discriminant is the number of the month, so `January as usize - 1` is the index
+            /// in `MON` and `ABMON`.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum Month {{
+                January = 1,
+                February = 2,
+                March = 3,
+                April = 4,
+                May = 5,
+                June = 6,
+                July = 7,
+                August = 8,
+                September = 9,
+                October = 10,
+                November = 11,
+                December = 12,
+            }}
+
+            /// An era of `LC_TIME::ERA`, like the `"令和"` era of `ja_JP`.
+            ///
+            /// The string `"+:2:2020/01/01:+*:令和:%EC%Ey年"` is decoded into its six fields.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct Era {{
+                /// `'+'` if the years of the era count up from `start`, or `'-'` if they count down.
+                pub direction: char,
+                /// The number of the year at `start`.
+                pub offset: i32,
+                /// The first day of the era.
+                pub start: EraDate,
+                /// The last day of the era.
+                pub end: EraDate,
+                /// The name of the era, used by `%EC`.
+                pub name: &'static str,
+                /// The format of a year of the era, used by `%EY`.
+                pub format: &'static str,
+            }}
+
+            /// The start or end of an [`Era`]. Dates are ordered from `MinusInfinity` to
+            /// `PlusInfinity`.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub enum EraDate {{
+                /// The beginning of time, `-*`.
+                MinusInfinity,
+                /// A day of the Gregorian calendar. Years before 1 are negative.
+                Date {{
+                    year: i32,
+                    month: u8,
+                    day: u8,
+                }},
+                /// The end of time, `+*`.
+                PlusInfinity,
+            }}
+
+            /// A `YESEXPR` or `NOEXPR` of `LC_MESSAGES`, compiled when the crate is generated so it can be
+            /// matched without a regex engine.
+            ///
+            /// `"^([+1yYｙＹ]|はい|ハイ)"` of `ja_JP` becomes the first characters `"+1yYｙＹ"` and the
+            /// words `["はい", "ハイ"]`.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct ResponsePattern {{
+                /// Each of these characters is accepted at the start of a response.
+                pub first_chars: &'static str,
+                /// Each of these words is accepted at the start of a response.
+                pub words: &'static [&'static str],
+                /// Whether the expression ends with `$`, so the response must be just the character or
+                /// the word.
+                pub whole: bool,
+            }}
+
+            impl ResponsePattern {{
+                /// Whether `input` matches the expression.
+                pub fn matches(&self, input: &str) -> bool {{
+                    let mut chars = input.chars();
+                    if let Some(c) = chars.next() {{
+                        if self.first_chars.contains(c) && (!self.whole || chars.as_str().is_empty()) {{
+                            return true;
+                        }}
+                    }}
+                    self.words.iter().any(|word| match self.whole {{
+                        true => input == *word,
+                        false => input.starts_with(word),
+                    }})
+                }}
+            }}
+
+            /// The value of a constant of a category, see [`Locale::typed_entries`].
+            ///
+            /// The variant follows the type of the constant, so `LC_MONETARY::CURRENCY_SYMBOL` is a
+            /// `Str`, `LC_MONETARY::MON_GROUPING` an `IntSlice` and `LC_TIME::ALT_DIGITS` a
+            /// `StrSlice`.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum DataValue {{
+                /// A `&'static str`.
+                Str(&'static str),
+                /// An `i64`.
+                Int(i64),
+                /// A `&'static [&'static str]`.
+                StrSlice(&'static [&'static str]),
+                /// A `&'static [i64]`.
+                IntSlice(&'static [i64]),
+                /// A `&'static [&'static [&'static str]]`.
+                StrSlice2d(&'static [&'static [&'static str]]),
+                /// A `&'static [&'static [i64]]`.
+                IntSlice2d(&'static [&'static [i64]]),
+            }}
+
+            /// The value returned by [`get`].
+            ///
+            /// This is [`DataValue`], which has the `Str`, `StrSlice` and `Int` variants and also the
+            /// other types of constants, like the `IntSlice` of `MON_GROUPING`, so `get` can return
+            /// every item that [`Locale::typed_entries`] lists.
+            pub type LocaleValue = DataValue;
+
+            /// Returns the value of the constant `key` of the category `category` of `locale`, like
+            /// `DataValue::StrSlice(&["Sun", "Mon", ...])` for `get(Locale::en_US, "LC_TIME", "ABDAY")`.
+            ///
+            /// This looks up one item of [`Locale::typed_entries`], for tools that get the category and
+            /// the name at runtime. Returns `None` if there is no such category or constant, or if the
+            /// constant is `None` for this locale.
+            ///
+            /// The items of the category are compared with `key` one by one, so a lookup costs
+            /// O(number of items of the category). To read many items, iterate over
+            /// [`Locale::typed_entries`] once instead.
+            pub fn get(locale: Locale, category: &str, key: &str) -> Option<LocaleValue> {{
+                locale
+                    .typed_entries(category)
+                    .find(|(name, _)| *name == key)
+                    .map(|(_, value)| value)
+            }}
+
+            /// A part of the `NAME_FMT` of `LC_NAME`, see [`LC_NAME::parse_name_fmt`].
+            ///
+            /// [`LC_NAME::parse_name_fmt`]: locales::POSIX::LC_NAME::parse_name_fmt
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum NameToken {{
+                /// Text that is copied as is.
+                Literal(&'static str),
+                /// `%t`: a space, unless the previous field was empty.
+                Separator,
+                /// `%d`: the salutation, like `"Mr."`.
+                Salutation,
+                /// `%f`: the family names.
+                FamilyName,
+                /// `%g`: the first given name.
+                GivenName,
+                /// `%m`: the additional given names.
+                OtherNames,
+                /// `%p`: the profession.
+                Profession,
+                /// Any other field, like `'a'` for the academic title `%a`.
+                Other(char),
+            }}
+
+            /// The country items of `LC_ADDRESS`, see [`LC_ADDRESS::country_info`].
+            ///
+            /// [`LC_ADDRESS::country_info`]: locales::POSIX::LC_ADDRESS::country_info
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct CountryInfo {{
+                /// `COUNTRY_NAME`: the name of the country in the language of the locale.
+                pub name: Option<&'static str>,
+                /// `COUNTRY_AB2`: the ISO 3166 two letter country code, like `"US"`.
+                pub ab2: Option<&'static str>,
+                /// `COUNTRY_AB3`: the ISO 3166 three letter country code, like `"USA"`.
+                pub ab3: Option<&'static str>,
+                /// `COUNTRY_NUM`: the ISO 3166 numeric country code, like `840`.
+                pub num: Option<i64>,
+                /// `COUNTRY_CAR`: the international license plate country code.
+                pub car: Option<&'static str>,
+                /// `COUNTRY_ISBN`: the ISBN registration group of the country.
+                pub isbn: Option<&'static str>,
+                /// `COUNTRY_POST`: the country designation of international postal addresses, written
+                /// before the postal code, like `"D"` in `de_DE` for `D-10115 Berlin`. Many locales
+                /// don't define it.
+                pub post: Option<&'static str>,
+            }}
+
+            /// A kind of salutation of `LC_NAME`, see [`LC_NAME::salutation`].
+            ///
+            /// [`LC_NAME::salutation`]: locales::POSIX::LC_NAME::salutation
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum Salutation {{
+                /// `NAME_GEN`: for all persons.
+                General,
+                /// `NAME_MR`: for males.
+                Mr,
+                /// `NAME_MRS`: for married females.
+                Mrs,
+                /// `NAME_MISS`: for unmarried females.
+                Miss,
+                /// `NAME_MS`: for all females.
+                Ms,
+            }}
+
+            /// Iterator over the [`NameToken`]s of a `NAME_FMT`.
+            #[derive(Copy, Clone, Debug)]
+            pub struct NameTokens {{
+                rest: &'static str,
+            }}
+
+            impl NameTokens {{
+                pub const fn new(format: &'static str) -> Self {{
+                    Self {{ rest: format }}
+                }}
+            }}
+
+            impl Iterator for NameTokens {{
+                type Item = NameToken;
+
+                fn next(&mut self) -> Option<NameToken> {{
+                    let mut chars = self.rest.chars();
+                    let token = match chars.next()? {{
+                        '%' => match chars.next() {{
+                            Some('t') => NameToken::Separator,
+                            Some('d') => NameToken::Salutation,
+                            Some('f') => NameToken::FamilyName,
+                            Some('g') => NameToken::GivenName,
+                            Some('m') => NameToken::OtherNames,
+                            Some('p') => NameToken::Profession,
+                            Some(c) => NameToken::Other(c),
+                            None => NameToken::Literal("%"),
+                        }},
+                        _ => {{
+                            let len = self.rest.find('%').unwrap_or(self.rest.len());
+                            let (token, rest) = self.rest.split_at(len);
+                            self.rest = rest;
+                            return Some(NameToken::Literal(token));
+                        }}
+                    }};
+                    self.rest = chars.as_str();
+                    Some(token)
+                }}
+            }}
+            "#,
+        )?;
+
+        for (name, item, desc) in [
+            ("DateFormat", "D_FMT", "date"),
+            ("TimeFormat", "T_FMT", "time"),
+            ("DateTimeFormat", "D_T_FMT", "date and time"),
+        ] {
+            write!(
+                f,
+                r#"
+
+                /// A {desc} format string (`{item}`) of `LC_TIME`.
+                #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+                pub struct {name}(pub &'static str);
+
+                impl {name} {{
+                    pub const fn as_str(&self) -> &'static str {{
+                        self.0
+                    }}
+
+                    /// Iterate over the literal text and the conversion specifications of the format.
+                    pub const fn tokens(&self) -> FormatTokens {{
+                        FormatTokens::new(self.0)
+                    }}
+                }}
+                "#,
+                name = name,
+                item = item,
+                desc = desc,
+            )?;
+        }
+
+        write!(
+            f,
+            r#"
+
+            /// Runtime overrides for the string items of a locale.
+            ///
+            /// Items are identified by their category and constant name, like `("LC_TIME", "D_FMT")`.
+            /// The `get_str_cow` family of methods returns the override if there is one, or otherwise
+            /// borrows the built-in data, so the same code path can serve both.
+            #[cfg(feature = "alloc")]
+            #[derive(Clone, Debug, Default, PartialEq, Eq)]
+            pub struct Overlay {{
+                categories: alloc::collections::BTreeMap<
+                    alloc::string::String,
+                    alloc::collections::BTreeMap<alloc::string::String, alloc::string::String>,
+                >,
+            }}
+
+            #[cfg(feature = "alloc")]
+            impl Overlay {{
+                pub fn new() -> Self {{
+                    Self::default()
+                }}
+
+                /// Override the item `key` of `category` with `value`.
+                pub fn set(&mut self, category: &str, key: &str, value: impl Into<alloc::string::String>) {{
+                    self.categories
+                        .entry(category.into())
+                        .or_default()
+                        .insert(key.into(), value.into());
+                }}
+
+                /// Remove the override of the item `key` of `category`, if any.
+                pub fn remove(&mut self, category: &str, key: &str) -> Option<alloc::string::String> {{
+                    self.categories.get_mut(category)?.remove(key)
+                }}
+
+                /// Returns the override of the item `key` of `category`, if any.
+                pub fn get(&self, category: &str, key: &str) -> Option<&str> {{
+                    self.categories.get(category)?.get(key).map(|x| x.as_str())
+                }}
+
+                /// Returns the override of the item `key` of `category`, or borrows `default`.
+                ///
+                /// ```
+                /// use pure_rust_locales::{{locales::en_US, Overlay}};
+                ///
+                /// let mut overlay = Overlay::new();
+                /// overlay.set("LC_TIME", "D_FMT", "%Y-%m-%d");
+                /// let d_fmt = overlay.get_str_cow("LC_TIME", "D_FMT", en_US::LC_TIME::D_FMT);
+                /// assert_eq!(d_fmt, "%Y-%m-%d");
+                /// ```
+                pub fn get_str_cow(
+                    &self,
+                    category: &str,
+                    key: &str,
+                    default: &'static str,
+                ) -> alloc::borrow::Cow<'static, str> {{
+                    match self.get(category, key) {{
+                        Some(x) => alloc::borrow::Cow::Owned(x.into()),
+                        None => alloc::borrow::Cow::Borrowed(default),
+                    }}
+                }}
+
+                /// Same as [`Overlay::get_str_cow`] for optional items.
+                ///
+                /// An override is returned even if the built-in item has no value.
+                pub fn get_opt_str_cow(
+                    &self,
+                    category: &str,
+                    key: &str,
+                    default: Option<&'static str>,
+                ) -> Option<alloc::borrow::Cow<'static, str>> {{
+                    match self.get(category, key) {{
+                        Some(x) => Some(alloc::borrow::Cow::Owned(x.into())),
+                        None => default.map(alloc::borrow::Cow::Borrowed),
+                    }}
+                }}
+            }}
+
+            /// The locale of every category, resolved from the environment variables like
+            /// `setlocale(LC_ALL, "")` does.
+            ///
+            /// For each category `LC_ALL` is used if it is set and not empty, then the variable of the
+            /// category itself, like `LC_TIME`, and then `LANG`. A category is `POSIX` if none of them
+            /// is set, or if the value is not a known locale.
+            #[cfg(feature = "std")]
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct LocaleSet {{
+                categories: [Locale; 12],
+                default: Locale,
+            }}
+
+            #[cfg(feature = "std")]
+            impl LocaleSet {{
+                /// The categories of glibc, in the order of a `LocaleSet`.
+                pub const CATEGORIES: [&'static str; 12] = [
+                    "LC_CTYPE",
+                    "LC_NUMERIC",
+                    "LC_TIME",
+                    "LC_COLLATE",
+                    "LC_MONETARY",
+                    "LC_MESSAGES",
+                    "LC_PAPER",
+                    "LC_NAME",
+                    "LC_ADDRESS",
+                    "LC_TELEPHONE",
+                    "LC_MEASUREMENT",
+                    "LC_IDENTIFICATION",
+                ];
+
+                /// Resolve the locales from the environment variables of this process.
+                pub fn from_env() -> Self {{
+                    Self::from_lookup(|name| std::env::var_os(name))
+                }}
+
+                /// Resolve the locales from the variables returned by `lookup`, like
+                /// [`from_env`](LocaleSet::from_env) does for the environment.
+                pub fn from_lookup<F>(lookup: F) -> Self
+                where
+                    F: Fn(&str) -> Option<std::ffi::OsString>,
+                {{
+                    let var = |name: &str| lookup(name).filter(|x| !x.is_empty());
+                    let resolve = |value: Option<std::ffi::OsString>| match value {{
+                        Some(x) => Locale::try_from_os_str(&x).unwrap_or(Locale::POSIX),
+                        None => Locale::POSIX,
+                    }};
+
+                    let all = var("LC_ALL");
+                    let lang = var("LANG");
+                    let mut categories = [Locale::POSIX; 12];
+                    for (locale, name) in categories.iter_mut().zip(Self::CATEGORIES) {{
+                        *locale = resolve(all.clone().or_else(|| var(name)).or_else(|| lang.clone()));
+                    }}
+                    Self {{
+                        categories,
+                        default: resolve(all.or(lang)),
+                    }}
+                }}
+
+                /// The locale of the category `name`, like `"LC_TIME"`.
+                ///
+                /// A category that is not in [`CATEGORIES`](LocaleSet::CATEGORIES) only follows `LC_ALL`
+                /// and `LANG`.
+                pub fn category(&self, name: &str) -> Locale {{
+                    match Self::CATEGORIES.iter().position(|x| *x == name) {{
+                        Some(i) => self.categories[i],
+                        None => self.default,
+                    }}
+                }}
+            }}
+
+            "#,
+        )
+    }
+
+    /// Functions added to every module of a category, next to the constants.
+    fn generate_category_helpers<W: Write>(
+        &self,
+        lang: &str,
+        category_name: &str,
+        fields: &BTreeMap<Field, Value>,
+        f: &mut CodeFormatter<W>,
+    ) -> std::fmt::Result {
+        match category_name {
+            "LC_IDENTIFICATION" => {
+                write!(
+                    f,
+                    r#"
+
+                    /// All the items of this category that have a value, as `(key, value)` pairs.
+                    pub const IDENTIFICATION: &[(&str, &str)] = &[
+                    "#,
+                )?;
+                f.indent(1);
+
+                for (field_name, value) in fields.iter() {
+                    if let Value::Literal(x) = value {
+                        write!(
+                            f,
+                            r#"
+                            ({:?}, {:?}),
+                            "#,
+                            field_name.to_lowercase(),
+                            x,
+                        )?;
+                    }
+                }
+
+                f.dedent(1);
+                write!(
+                    f,
+                    r#"
+                    ];
+                    "#,
+                )?;
+            }
+            "LC_TIME" => write!(
+                f,
+                r#"
+
+                /// The date format `D_FMT`.
+                pub const fn d_fmt() -> crate::DateFormat {{
+                    crate::DateFormat(D_FMT)
+                }}
+
+                /// The time format `T_FMT`.
+                pub const fn t_fmt() -> crate::TimeFormat {{
+                    crate::TimeFormat(T_FMT)
+                }}
+
+                /// The date and time format `D_T_FMT`.
+                pub const fn d_t_fmt() -> crate::DateTimeFormat {{
+                    crate::DateTimeFormat(D_T_FMT)
+                }}
+
+                /// The standalone name of the month `n` (1 is January), used without a day.
+                ///
+                /// This is `ALT_MON` if the locale has it and `MON` otherwise. Returns `None` if `n` is
+                /// not in `1..=12`.
+                pub const fn standalone_mon(n: u8) -> Option<&'static str> {{
+                    match ALT_MON {{
+                        Some(months) => crate::helpers::month(months, n),
+                        None => crate::helpers::month(MON, n),
+                    }}
+                }}
+
+                /// The abbreviated standalone name of the month `n` (1 is January), used without a day.
+                ///
+                /// This is `AB_ALT_MON` if the locale has it and `ABMON` otherwise. Returns `None` if
+                /// `n` is not in `1..=12`.
+                pub const fn standalone_abmon(n: u8) -> Option<&'static str> {{
+                    match AB_ALT_MON {{
+                        Some(months) => crate::helpers::month(months, n),
+                        None => crate::helpers::month(ABMON, n),
+                    }}
+                }}
+
+                /// The name of the month `n` (1 is January) of `MON` with its first character in
+                /// uppercase, like `"Janvier"` for `"janvier"` in `fr_FR`, to start a sentence.
+                ///
+                /// This is only a presentation helper: it doesn't follow the capitalization rules of
+                /// the language. Returns `None` if `n` is not in `1..=12`.
+                #[cfg(feature = "alloc")]
+                pub fn month_titlecase(n: u8) -> Option<alloc::string::String> {{
+                    crate::helpers::month(MON, n).map(crate::helpers::titlecase)
+                }}
+
+                /// The strings for before and after noon of `AM_PM`, like `["AM", "PM"]` for `en_US`.
+                ///
+                /// Returns `None` if the locale doesn't use them, like `de_DE` which has two empty
+                /// strings.
+                pub const fn am_pm() -> Option<[&'static str; 2]> {{
+                    match AM_PM {{
+                        [am, pm] if !am.is_empty() || !pm.is_empty() => Some([am, pm]),
+                        _ => None,
+                    }}
+                }}
+
+                /// [`am_pm`] in lowercase, like `["am", "pm"]` for `en_US`.
+                ///
+                /// This is only a presentation helper for interfaces that prefer lowercase, glibc
+                /// itself always uses the case of `AM_PM`.
+                #[cfg(feature = "alloc")]
+                pub fn am_pm_lower() -> Option<[alloc::string::String; 2]> {{
+                    am_pm().map(|[am, pm]| [am.to_lowercase(), pm.to_lowercase()])
+                }}
+
+                /// The alternative digits of the number `n` from `ALT_DIGITS`, like `"۰۰"` for 0 in
+                /// `fa_IR`, as used by `%Od`.
+                ///
+                /// Returns `None` if `n` is not in the table, which is empty for most locales.
+                pub const fn alt_digit(n: usize) -> Option<&'static str> {{
+                    if n < ALT_DIGITS.len() {{
+                        Some(ALT_DIGITS[n])
+                    }} else {{
+                        None
+                    }}
+                }}
+
+                /// Whether the locale numbers weeks like ISO 8601: weeks start on Monday and the first
+                /// week of the year is the first week with at least 4 days in the year.
+                ///
+                /// This is derived from the minimal number of days of the first week (the third value
+                /// of `WEEK`) and the first day of the week (from `WEEK` and `FIRST_WEEKDAY`). It
+                /// returns `false` if the locale has no `WEEK`.
+                pub const fn uses_iso8601_weeks() -> bool {{
+                    crate::helpers::uses_iso8601_weeks(WEEK, FIRST_WEEKDAY)
+                }}
+
+                /// The format of `date(1)`, `DATE_FMT`, with the time zone filled in: `%Z` is replaced
+                /// by `abbreviation` (like `"CET"`) and `%z` by `offset` (like `"+0100"`). They are
+                /// replaced by nothing if they are `None`.
+                ///
+                /// If the locale has no `DATE_FMT` the default of glibc is used,
+                /// `"%a %b %e %H:%M:%S %Z %Y"`.
+                #[cfg(feature = "alloc")]
+                pub fn date_fmt_with_timezone(abbreviation: Option<&str>, offset: Option<&str>) -> alloc::string::String {{
+                    let format = DATE_FMT.unwrap_or("%a %b %e %H:%M:%S %Z %Y");
+                    crate::helpers::substitute_timezone(format, abbreviation, offset)
+                }}
+
+                /// The first day of the week of this locale, from 0 for Sunday to 6 for Saturday.
+                ///
+                /// This is derived from `WEEK` and `FIRST_WEEKDAY`, and is Sunday if both are missing.
+                pub const fn first_weekday_sunday0() -> u8 {{
+                    crate::helpers::first_weekday(WEEK, FIRST_WEEKDAY)
+                }}
+
+                /// The abbreviated day names, starting on the first day of the week of this locale.
+                pub const fn ordered_abday() -> [&'static str; 7] {{
+                    crate::helpers::rotate_weekdays(ABDAY, FIRST_WEEKDAY)
+                }}
+
+                /// The full day names, starting on the first day of the week of this locale.
+                pub const fn ordered_day() -> [&'static str; 7] {{
+                    crate::helpers::rotate_weekdays(DAY, FIRST_WEEKDAY)
+                }}
+                "#,
+            )?,
+            "LC_MONETARY" => write!(
+                f,
+                r#"
+
+                /// The number of digits after the decimal point of amounts in the local currency.
+                ///
+                /// If `FRAC_DIGITS` is not specified (`-1`), this falls back to the ISO 4217 minor units
+                /// of `INT_CURR_SYMBOL` (see [`iso4217_minor_units`](crate::iso4217_minor_units)), or
+                /// to 2 without a currency.
+                pub fn effective_frac_digits() -> u8 {{
+                    crate::helpers::effective_frac_digits(FRAC_DIGITS, INT_CURR_SYMBOL)
+                }}
+
+                /// Whether amounts of money are grouped at all.
+                pub const fn has_mon_grouping() -> bool {{
+                    mon_grouping().is_grouped()
+                }}
+
+                /// The sizes of the digit groups of amounts of money, starting from the decimal point.
+                pub const fn mon_group_sizes() -> crate::GroupSizes {{
+                    mon_grouping().group_sizes()
+                }}
+
+                /// `MON_GROUPING` as a [`Grouping`](crate::Grouping).
+                pub const fn mon_grouping() -> crate::Grouping {{
+                    crate::Grouping::new(typed_mon_grouping())
+                }}
+
+                /// `P_SIGN_POSN` as a [`SignPosition`](crate::SignPosition), like
+                /// [`BeforeAll`](crate::SignPosition::BeforeAll) for `en_US`.
+                pub const fn p_sign_posn() -> Option<crate::SignPosition> {{
+                    crate::SignPosition::from_i64(P_SIGN_POSN)
+                }}
+
+                /// `N_SIGN_POSN` as a [`SignPosition`](crate::SignPosition).
+                pub const fn n_sign_posn() -> Option<crate::SignPosition> {{
+                    crate::SignPosition::from_i64(N_SIGN_POSN)
+                }}
+
+                /// `P_CS_PRECEDES` as a [`CsPrecedes`](crate::CsPrecedes), like
+                /// [`Precedes`](crate::CsPrecedes::Precedes) for `en_US`.
+                pub const fn p_cs_precedes() -> Option<crate::CsPrecedes> {{
+                    crate::CsPrecedes::from_i64(P_CS_PRECEDES)
+                }}
+
+                /// `N_CS_PRECEDES` as a [`CsPrecedes`](crate::CsPrecedes).
+                pub const fn n_cs_precedes() -> Option<crate::CsPrecedes> {{
+                    crate::CsPrecedes::from_i64(N_CS_PRECEDES)
+                }}
+
+                /// How negative amounts of money are written, like
+                /// [`LeadingMinus`](crate::NegativeDisplay::LeadingMinus) for `"-$1.00"` in `en_US`.
+                pub const fn negative_display() -> crate::NegativeDisplay {{
+                    crate::helpers::negative_display(NEGATIVE_SIGN, N_SIGN_POSN, N_CS_PRECEDES)
+                }}
+
+                /// The grouping to format amounts of money with: [`typed_mon_grouping`], or no grouping
+                /// at all if `MON_THOUSANDS_SEP` is empty, like in `kab_DZ`.
+                ///
+                /// If `MON_GROUPING` is missing or empty, the `GROUPING` of `LC_NUMERIC` of the locale
+                /// that defines this category is used instead. Some locales group money differently,
+                /// like `hi_IN` which groups numbers by 3 but money in the Indian style.
+                pub const fn effective_grouping() -> &'static [crate::GroupSize] {{
+                    if MON_THOUSANDS_SEP.is_empty() {{
+                        &[crate::GroupSize::NoMore]
+                    }} else if typed_mon_grouping().is_empty() {{
+                        numeric_grouping()
+                    }} else {{
+                        typed_mon_grouping()
+                    }}
+                }}
+
+                /// Format the sample amount 1234.56 in the local currency, like `"$1,234.56"` for
+                /// `en_US`, to preview the formatting rules of this locale.
+                ///
+                /// The amount is rounded to [`effective_frac_digits`] and grouped with
+                /// [`effective_grouping`].
+                #[cfg(feature = "alloc")]
+                pub fn sample_amount_string(negative: bool) -> alloc::string::String {{
+                    let format = crate::helpers::MonetaryFormat {{
+                        currency_symbol: CURRENCY_SYMBOL,
+                        decimal_point: MON_DECIMAL_POINT,
+                        thousands_sep: MON_THOUSANDS_SEP,
+                        grouping: effective_grouping(),
+                        frac_digits: effective_frac_digits(),
+                        sign: if negative {{ NEGATIVE_SIGN }} else {{ POSITIVE_SIGN }},
+                        cs_precedes: if negative {{ N_CS_PRECEDES }} else {{ P_CS_PRECEDES }},
+                        sep_by_space: if negative {{ N_SEP_BY_SPACE }} else {{ P_SEP_BY_SPACE }},
+                        sign_posn: if negative {{ N_SIGN_POSN }} else {{ P_SIGN_POSN }},
+                    }};
+                    format.format(123456)
+                }}
+                "#,
+            )?,
+            "LC_MEASUREMENT" => write!(
+                f,
+                r#"
+
+                /// Whether the locale uses the metric system (`MEASUREMENT` is 1) instead of US
+                /// customary units (`MEASUREMENT` is 2).
+                ///
+                /// `POSIX` uses the metric system, like glibc does for the `C` locale.
+                pub const fn is_metric() -> bool {{
+                    MEASUREMENT == 1
+                }}
+                "#,
+            )?,
+            "LC_ADDRESS" => write!(
+                f,
+                r#"
+
+                /// The old name of [`COUNTRY_AB2`].
+                #[deprecated(note = "renamed to `COUNTRY_AB2`")]
+                pub const COUNTRY_ABTWO: Option<&str> = COUNTRY_AB2;
+
+                /// All the country items of this category.
+                pub const fn country_info() -> crate::CountryInfo {{
+                    crate::CountryInfo {{
+                        name: COUNTRY_NAME,
+                        ab2: COUNTRY_AB2,
+                        ab3: COUNTRY_AB3,
+                        num: COUNTRY_NUM,
+                        car: COUNTRY_CAR,
+                        isbn: COUNTRY_ISBN,
+                        post: COUNTRY_POST,
+                    }}
+                }}
+                "#,
+            )?,
+            "LC_TELEPHONE" => write!(
+                f,
+                r#"
+
+                /// The country calling code (`INT_PREFIX`), like `"49"` in `de_DE`. It is empty in
+                /// `POSIX`.
+                pub const fn country_calling_code() -> &'static str {{
+                    INT_PREFIX
+                }}
+
+                /// The prefix to dial an international number (`INT_SELECT`), like `"00"` in `de_DE`
+                /// or `"11"` in `en_US`. It is empty in `POSIX`.
+                pub const fn international_dial_prefix() -> &'static str {{
+                    INT_SELECT
+                }}
+
+                /// Format a domestic telephone number following `TEL_DOM_FMT`, like `"(212) 5551234"`
+                /// in `en_US` or `"030 1234567"` in `de_DE`.
+                ///
+                /// `area` is the area code without the national prefix: `%a` in the format is replaced
+                /// by it as is, and `%A` with the prefix `0` in front. `%l` is replaced by `number`.
+                /// With an empty `TEL_DOM_FMT` this returns the area code and number separated by a
+                /// space.
+                #[cfg(feature = "alloc")]
+                pub fn format_domestic(area: &str, number: &str) -> alloc::string::String {{
+                    let format = match TEL_DOM_FMT {{
+                        "" => "%a%t%l",
+                        x => x,
+                    }};
+                    crate::helpers::format_phone_number(format, area, number)
+                }}
+                "#,
+            )?,
+            "LC_NAME" => write!(
+                f,
+                r#"
+
+                /// Split `NAME_FMT` into its fields and literal text, in the order a name is written.
+                ///
+                /// `en_US` writes the given name first, `"%d%t%g%t%m%t%f"`, and `ja_JP` the family
+                /// name, `"%p%t%f%t%g"`.
+                pub const fn parse_name_fmt() -> crate::NameTokens {{
+                    crate::NameTokens::new(NAME_FMT)
+                }}
+
+                /// The salutation of the kind `kind`, like `"Herr"` for [`Mr`](crate::Salutation::Mr)
+                /// in `de_DE`. It is empty if the locale doesn't define it.
+                pub const fn salutation(kind: crate::Salutation) -> &'static str {{
+                    match kind {{
+                        crate::Salutation::General => NAME_GEN,
+                        crate::Salutation::Mr => NAME_MR,
+                        crate::Salutation::Mrs => NAME_MRS,
+                        crate::Salutation::Miss => NAME_MISS,
+                        crate::Salutation::Ms => NAME_MS,
+                    }}
+                }}
+                "#,
+            )?,
+            "LC_NUMERIC" => write!(
+                f,
+                r#"
+
+                /// Whether numbers are grouped at all.
+                ///
+                /// A `GROUPING` of `[0]` or `[-1]` disables grouping.
+                pub const fn has_grouping() -> bool {{
+                    grouping().is_grouped()
+                }}
+
+                /// The sizes of the digit groups of numbers, starting from the decimal point.
+                pub const fn group_sizes() -> crate::GroupSizes {{
+                    grouping().group_sizes()
+                }}
+
+                /// `GROUPING` as a [`Grouping`](crate::Grouping).
+                pub const fn grouping() -> crate::Grouping {{
+                    crate::Grouping::new(typed_grouping())
+                }}
+
+                /// Write the integer part `integer_digits` to `out` with `THOUSANDS_SEP` between the
+                /// groups of `GROUPING`, like `"1,234,567"` for `"1234567"` in `en_US`.
+                ///
+                /// This doesn't allocate, so it can format into a buffer on the stack.
+                pub fn write_grouped<W: core::fmt::Write + ?Sized>(out: &mut W, integer_digits: &str) -> core::fmt::Result {{
+                    crate::helpers::write_grouped(out, integer_digits, THOUSANDS_SEP, group_sizes())
+                }}
+
+                /// Whether the decimal point is a comma, like `"1,5"` in `de_DE`.
+                pub const fn decimal_is_comma() -> bool {{
+                    matches!(DECIMAL_POINT.as_bytes(), b",")
+                }}
+
+                /// The decimal separators to accept when reading a number typed by a user:
+                /// `DECIMAL_POINT`, and `'.'` as alternative if the decimal point is something else.
+                ///
+                /// `de_DE` gives `(',', Some('.'))` so `"1,5"` and `"1.5"` are both read as one and a
+                /// half; a forgiving parser should then not accept `.` as group separator. `en_US`
+                /// gives `('.', None)`, and so does an empty decimal point.
+                pub fn input_separators() -> (char, Option<char>) {{
+                    match DECIMAL_POINT.chars().next() {{
+                        Some('.') | None => ('.', None),
+                        Some(c) => (c, Some('.')),
+                    }}
+                }}
+
+                /// The thousands separator, exactly as in the locale data.
+                ///
+                /// This may be a multi-byte, non-ASCII space like U+00A0 NO-BREAK SPACE or
+                /// U+202F NARROW NO-BREAK SPACE (used by `fr_FR`).
+                pub const fn thousands_sep_str() -> &'static str {{
+                    THOUSANDS_SEP
+                }}
+                "#,
+            )?,
+            _ => {}
+        }
+
+        if category_name == "LC_TIME" {
+            let eras: Vec<_> = match fields.get("ERA") {
+                Some(Value::Array(x)) => x
+                    .iter()
+                    .map(|x| parse_era(x))
+                    .sorted_by(|a, b| a.0.cmp(&b.0))
+                    .map(|x| x.1)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            write!(
+                f,
+                r#"
+
+                /// `ERA` decoded into [`Era`](crate::Era)s, sorted by their earliest date (oldest
+                /// first). `ERA` itself keeps the order of glibc, which usually lists the current era
+                /// first.
+                pub const ERA_ENTRIES: &[crate::Era] = &[{entries}];
+
+                /// Iterate over the eras of [`ERA_ENTRIES`], oldest first.
+                pub fn eras() -> impl Iterator<Item = crate::Era> {{
+                    ERA_ENTRIES.iter().copied()
+                }}
+                "#,
+                entries = eras.join(", "),
+            )?;
+        }
+
+        if category_name == "LC_MESSAGES" {
+            let pattern = |field_name: &str| match fields.get(field_name) {
+                Some(Value::Literal(x)) => {
+                    let (first_chars, words, whole) = compile_response_expr(x);
+                    format!(
+                        "crate::ResponsePattern {{ first_chars: {:?}, words: &{:?}, whole: {} }}",
+                        first_chars, words, whole
+                    )
+                }
+                _ => "crate::ResponsePattern { first_chars: \"\", words: &[], whole: false }"
+                    .to_string(),
+            };
+            write!(
                 f,
                 r#"
-                {lang:?} => Ok(Locale::{norm}),
+
+                /// `YESEXPR` compiled into a [`ResponsePattern`](crate::ResponsePattern).
+                pub const YES_PATTERN: crate::ResponsePattern = {yes};
+
+                /// `NOEXPR` compiled into a [`ResponsePattern`](crate::ResponsePattern).
+                pub const NO_PATTERN: crate::ResponsePattern = {no};
+
+                /// Whether `input` is a yes response, like `"j"` or `"Ja"` in `de_DE`.
+                pub fn accepts_yes(input: &str) -> bool {{
+                    YES_PATTERN.matches(input)
+                }}
+
+                /// Whether `input` is a no response, like `"n"` or `"nein"` in `de_DE`.
+                pub fn accepts_no(input: &str) -> bool {{
+                    NO_PATTERN.matches(input)
+                }}
                 "#,
-                lang = lang,
-                norm = norm,
+                yes = pattern("YESEXPR"),
+                no = pattern("NOEXPR"),
             )?;
         }
 
-        f.dedent(3);
+        let typed_grouping = match category_name {
+            "LC_NUMERIC" => Some(("typed_grouping", "GROUPING")),
+            "LC_MONETARY" => Some(("typed_mon_grouping", "MON_GROUPING")),
+            _ => None,
+        };
+        if let Some((name, field_name)) = typed_grouping {
+            let grouping = match fields.get(field_name) {
+                Some(Value::Literal(x)) => vec![x.clone()],
+                Some(Value::Array(x)) => x.clone(),
+                _ => Vec::new(),
+            };
+            write!(
+                f,
+                r#"
+
+                /// `{field}` with the terminator and repeated sizes decoded, like `[Repeat(3), NoMore]`
+                /// for `[3, -1]`.
+                pub const fn {name}() -> &'static [crate::GroupSize] {{
+                    &[{sizes}]
+                }}
+                "#,
+                field = field_name,
+                name = name,
+                sizes = group_sizes(&grouping)
+                    .iter()
+                    .map(|x| format!("crate::GroupSize::{}", x))
+                    .format(", "),
+            )?;
+
+            if category_name == "LC_MONETARY" {
+                let numeric = match self
+                    .fields(lang, "LC_NUMERIC")
+                    .and_then(|x| x.get("GROUPING"))
+                {
+                    Some(Value::Literal(x)) => vec![x.clone()],
+                    Some(Value::Array(x)) => x.clone(),
+                    _ => Vec::new(),
+                };
+                write!(
+                    f,
+                    r#"
+
+                    /// `GROUPING` of `LC_NUMERIC` decoded, the fallback of [`effective_grouping`].
+                    const fn numeric_grouping() -> &'static [crate::GroupSize] {{
+                        &[{}]
+                    }}
+                    "#,
+                    group_sizes(&numeric)
+                        .iter()
+                        .map(|x| format!("crate::GroupSize::{}", x))
+                        .format(", "),
+                )?;
+            }
+
+            if category_name == "LC_NUMERIC" {
+                write!(
+                    f,
+                    r#"
+
+                    /// The kind of grouping of `GROUPING`, like [`Indian`](crate::GroupingStyle::Indian)
+                    /// for `[3, 2]` in `en_IN`.
+                    pub const fn grouping_style() -> crate::GroupingStyle {{
+                        crate::GroupingStyle::{}
+                    }}
+                    "#,
+                    grouping_style(&grouping),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The fields of a category of a locale, following the re-exports.
+    fn fields(&self, lang: &str, category_name: &str) -> Option<&BTreeMap<Field, Value>> {
+        match self.by_language.get(lang)?.get(category_name)? {
+            Category::Fields(fields) => Some(fields),
+            Category::Link(other) => {
+                let (other, _) = self.normalized_langs.iter().find(|(_, x)| *x == other)?;
+                self.fields(other, category_name)
+            }
+        }
+    }
+
+    /// The title of a locale from `LC_IDENTIFICATION`.
+    fn title(&self, lang: &str) -> String {
+        match self
+            .fields(lang, "LC_IDENTIFICATION")
+            .and_then(|x| x.get("TITLE"))
+        {
+            Some(Value::Literal(title)) => title.clone(),
+            _ => match lang == "POSIX" {
+                true => "POSIX Standard Locale".to_string(),
+                false => "".to_string(),
+            },
+        }
+    }
+
+    fn script(&self, lang: &str) -> Option<&'static str> {
+        let (language, _, modifier) = &self.components[lang];
+
+        modifier
+            .as_deref()
+            .and_then(|x| tables::lookup(tables::MODIFIER_SCRIPTS, x))
+            .or_else(|| tables::lookup(tables::LOCALE_SCRIPTS, lang))
+            .or_else(|| tables::lookup(tables::LANGUAGE_SCRIPTS, language))
+    }
+
+    fn generate_lc_time_struct<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        let fields = &self.field_metadata["LC_TIME"];
+
+        write!(
+            f,
+            r#"
+
+            /// All the `LC_TIME` items of a locale.
+            ///
+            /// Use [`lc_time_struct!`] to build one from the constants of a locale, also in `const`
+            /// context.
+            #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+            pub struct TimeFields {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (field_name, meta) in fields.iter() {
+            write!(
+                f,
+                r#"
+                pub {name}: {ty},
+                "#,
+                name = field_name.to_lowercase(),
+                ty = TypeFormatter::with_static_lifetime(meta),
+            )?;
+        }
+
+        f.dedent(1);
         write!(
             f,
             r#"
-                        _ => Err(UnknownLocale),
-                    }}
-                }}
             }}
 
+            /// Expands to a [`TimeFields`] struct literal holding the `LC_TIME` items of a locale.
+            ///
+            /// ```
+            /// use pure_rust_locales::{{lc_time_struct, TimeFields}};
+            ///
+            /// const DE_DE: TimeFields = lc_time_struct!(Locale::de_DE);
+            /// assert_eq!(DE_DE.d_fmt, "%d.%m.%Y");
+            /// ```
             #[macro_export]
-            macro_rules! locale_match {{
-                ($locale:expr => $($item:ident)::+) => {{{{
-                    match $locale {{
+            macro_rules! lc_time_struct {{
+                (Locale::$locale:ident) => {{
+                    $crate::TimeFields {{
             "#,
         )?;
         f.indent(3);
 
-        for (_, norm) in self.normalized_langs.iter() {
+        for field_name in fields.keys() {
             write!(
                 f,
                 r#"
-                $crate::Locale::{norm} => $crate::{norm}::$($item)::+,
+                {name}: $crate::locales::$locale::LC_TIME::{field},
                 "#,
-                norm = norm,
+                name = field_name.to_lowercase(),
+                field = field_name,
             )?;
         }
-        f.dedent(3);
 
+        f.dedent(3);
         write!(
             f,
             r#"
                     }}
-                }}}}
+                }};
+            }}
+            "#,
+        )
+    }
+
+    /// The `LocaleData` trait, with the most commonly used items of the categories, and its
+    /// implementation for `Locale`.
+    fn generate_locale_data<W: Write>(&self, f: &mut CodeFormatter<W>) -> std::fmt::Result {
+        let items = LOCALE_DATA_FIELDS
+            .iter()
+            .filter_map(|&(category_name, field_name)| {
+                let meta = self.field_metadata.get(category_name)?.get(field_name)?;
+                Some((category_name, field_name, meta))
+            })
+            .collect::<Vec<_>>();
+
+        write!(
+            f,
+            r#"
+
+            /// The most commonly used items of a locale, for use with dynamic dispatch as
+            /// `&dyn LocaleData`.
+            ///
+            /// The methods have the name of the item in lowercase, and return the same value as the
+            /// constant of the category module.
+            ///
+            /// ```
+            /// use pure_rust_locales::{{Locale, LocaleData}};
+            ///
+            /// let data: &dyn LocaleData = &Locale::de_DE;
+            /// assert_eq!(data.mon()[0], "Januar");
+            /// ```
+            pub trait LocaleData {{
+            "#,
+        )?;
+        f.indent(1);
+
+        for (category_name, field_name, meta) in items.iter() {
+            write!(
+                f,
+                r#"
+                /// `{category}::{field}`
+                fn {name}(&self) -> {ty};
+                "#,
+                category = category_name,
+                field = field_name,
+                name = field_name.to_lowercase(),
+                ty = TypeFormatter::with_static_lifetime(meta),
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
             }}
 
+            #[cfg(all({features}))]
+            impl LocaleData for Locale {{
+            "#,
+            features = items
+                .iter()
+                .map(|(category_name, _, _)| category_feature(category_name))
+                .unique()
+                .map(|x| format!("feature = {:?}", x))
+                .join(", "),
+        )?;
+        f.indent(1);
+
+        for (category_name, field_name, meta) in items.iter() {
+            write!(
+                f,
+                r#"
+                fn {name}(&self) -> {ty} {{
+                    enabled_locale_match!(*self => {category}::{field})
+                }}
+                "#,
+                category = category_name,
+                field = field_name,
+                name = field_name.to_lowercase(),
+                ty = TypeFormatter::with_static_lifetime(meta),
+            )?;
+        }
+
+        f.dedent(1);
+        write!(
+            f,
+            r#"
+            }}
             "#,
         )
     }
 }
 
+/// Decode the values of a `GROUPING` or `MON_GROUPING` into `GroupSize` variants.
+///
+/// This is the only place that interprets the sentinels of glibc, `Grouping` and `GroupSizes` only
+/// see the decoded sizes. A `0` repeats the previous size for the remaining digits, which is already
+/// implied by reaching the end of the slice, so it is dropped together with the values after it. A
+/// leading `0` or a `-1` (`CHAR_MAX`) ends the grouping.
+fn group_sizes(grouping: &[String]) -> Vec<String> {
+    let mut sizes = Vec::new();
+    for value in grouping {
+        match value.parse::<i64>() {
+            Ok(0) if !sizes.is_empty() => break,
+            Ok(x @ 1..=126) => sizes.push(format!("Repeat({})", x)),
+            _ => {
+                sizes.push("NoMore".to_string());
+                break;
+            }
+        }
+    }
+    sizes
+}
+
+/// The earliest date of an era: `(-1, ..)` is `-*`, `(1, ..)` is `+*`, and `(0, year, month, day)`
+/// is a date.
+type EraKey = (i8, i32, u8, u8);
+
+/// The cargo feature that enables the locale with the normalized name `norm`, like
+/// `locale-de_DE_euro` for `de_DE@euro`.
+fn locale_feature(norm: &str) -> String {
+    format!("locale-{}", norm)
+}
+
+/// The cargo feature that enables the category `category_name`, like `lc_time` for `LC_TIME`.
+fn category_feature(category_name: &str) -> String {
+    category_name.to_lowercase()
+}
+
+/// Decode an entry of `ERA`, like `"+:2:2020/01/01:+*:令和:%EC%Ey年"`, into the literal of an
+/// `Era`. Also returns the earliest date of the era to sort the eras.
+fn parse_era(entry: &str) -> (EraKey, String) {
+    let parse_date = |date: &str| match date {
+        "-*" => ((-1, 0, 0, 0), "crate::EraDate::MinusInfinity".to_string()),
+        "+*" => ((1, 0, 0, 0), "crate::EraDate::PlusInfinity".to_string()),
+        _ => {
+            let (year, month, day) = date
+                .rsplitn(3, '/')
+                .collect_tuple()
+                .map(|(day, month, year)| (year.parse(), month.parse(), day.parse()))
+                .unwrap_or_else(|| panic!("invalid era date {:?}", date));
+            let (year, month, day) = match (year, month, day) {
+                (Ok(year), Ok(month), Ok(day)) => (year, month, day),
+                _ => panic!("invalid era date {:?}", date),
+            };
+            let literal = format!(
+                "crate::EraDate::Date {{ year: {}, month: {}, day: {} }}",
+                year, month, day
+            );
+            ((0, year, month, day), literal)
+        }
+    };
+
+    let (direction, offset, start, end, name, format) =
+        match *entry.splitn(6, ':').collect::<Vec<_>>() {
+            [direction, offset, start, end, name, format] => {
+                (direction, offset, start, end, name, format)
+            }
+            _ => panic!("invalid era {:?}", entry),
+        };
+    let offset: i32 = offset
+        .parse()
+        .unwrap_or_else(|_| panic!("invalid era offset {:?}", entry));
+    let (start_key, start) = parse_date(start);
+    let (end_key, end) = parse_date(end);
+    let literal = format!(
+        "crate::Era {{ direction: {:?}, offset: {}, start: {}, end: {}, name: {:?}, format: {:?} }}",
+        direction.chars().next().unwrap_or('+'),
+        offset,
+        start,
+        end,
+        name,
+        format,
+    );
+    (start_key.min(end_key), literal)
+}
+
+/// Compile a `YESEXPR` or `NOEXPR` into the first characters, words and `whole` flag of a
+/// `ResponsePattern`.
+///
+/// Only the subset of POSIX regular expressions used by the locales is supported:
+/// `^(alternative|...)$`, where the parentheses and `$` are optional, and an alternative is a
+/// sequence of characters and bracket expressions without ranges, each maybe followed by `?`.
+fn compile_response_expr(expr: &str) -> (String, Vec<String>, bool) {
+    let rest = expr
+        .strip_prefix('^')
+        .unwrap_or_else(|| panic!("unsupported response expression {:?}", expr));
+    let (rest, whole) = match rest.strip_suffix('$') {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+    let rest = match rest.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+        Some(rest) => rest,
+        None => rest,
+    };
+
+    let mut first_chars = String::new();
+    let mut words = Vec::new();
+    for alternative in rest.split('|') {
+        // Every atom is a set of characters, and whether it may be left out.
+        let mut atoms = Vec::<(Vec<char>, bool)>::new();
+        let mut chars = alternative.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '[' => {
+                    let set: Vec<char> = chars.by_ref().take_while(|&x| x != ']').collect();
+                    if set.first() == Some(&'^') || set.iter().skip(1).any(|&x| x == '-') {
+                        panic!("unsupported bracket expression in {:?}", expr);
+                    }
+                    atoms.push((set, false));
+                }
+                '?' => match atoms.last_mut() {
+                    Some(atom) => atom.1 = true,
+                    None => panic!("unsupported response expression {:?}", expr),
+                },
+                '(' | ')' | '*' | '+' | '.' | '\\' | '{' | '$' | '^' => {
+                    panic!("unsupported response expression {:?}", expr)
+                }
+                c => atoms.push((vec![c], false)),
+            }
+        }
+
+        match atoms[..] {
+            [(ref set, false)] => first_chars.extend(set),
+            _ => {
+                let mut expanded = vec![String::new()];
+                for (set, optional) in atoms {
+                    let mut next = Vec::new();
+                    for word in expanded {
+                        if optional {
+                            next.push(word.clone());
+                        }
+                        for &c in &set {
+                            let mut word = word.clone();
+                            word.push(c);
+                            next.push(word);
+                        }
+                    }
+                    expanded = next;
+                }
+                words.extend(expanded.into_iter().filter(|x| !x.is_empty()));
+            }
+        }
+    }
+    (first_chars, words, whole)
+}
+
+/// Classify a `GROUPING` as one of the variants of `GroupingStyle`.
+fn grouping_style(grouping: &[String]) -> &'static str {
+    let mut sizes = group_sizes(grouping);
+    // the last size is repeated, so `[3, 3]` is the same as `[3]`
+    while sizes.len() >= 2 && sizes[sizes.len() - 1] == sizes[sizes.len() - 2] {
+        sizes.pop();
+    }
+    match sizes
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] | ["NoMore", ..] => "None",
+        ["Repeat(3)"] => "Western",
+        ["Repeat(3)", "Repeat(2)"] => "Indian",
+        _ => "Custom",
+    }
+}
+
+/// An item with several rows that is sorted (stably) during generation with `compare`, instead of
+/// keeping the order of the source file, see [`CodeGenerator::with_sorted_rows`].
+///
+/// The rows are the values of an item with one line, like the eras of `era`, or the lines of an
+/// item with several lines, which are compared by their first value. No item is sorted by
+/// default.
+pub struct SortedRows {
+    /// The category of the item, like `"LC_TIME"`.
+    pub category: &'static str,
+    /// The name of the constant of the item, like `"ERA"`.
+    pub item: &'static str,
+    pub compare: fn(&str, &str) -> std::cmp::Ordering,
+}
+
+/// Sort `LC_TIME::ERA` by the earliest date of each era, oldest first. Most files list the current
+/// era first.
+///
+/// `ERA_ENTRIES` is always sorted this way; this option also sorts the strings of `ERA`.
+#[allow(dead_code)]
+pub const ERA_BY_START: SortedRows = SortedRows {
+    category: "LC_TIME",
+    item: "ERA",
+    compare: |a, b| parse_era(a).0.cmp(&parse_era(b).0),
+};
+
+/// The items that are arrays of a known length, like the 7 days of `DAY`. They are generated as
+/// `&[&str; 7]` instead of `&[&str]`, unless a locale has another number of values.
+const FIXED_LENGTHS: &[(&str, &str, usize)] = &[
+    ("LC_TIME", "ABDAY", 7),
+    ("LC_TIME", "DAY", 7),
+    ("LC_TIME", "ABMON", 12),
+    ("LC_TIME", "MON", 12),
+];
+
+/// The arrays that are generated as an empty slice instead of `None` in the locales that don't
+/// have them, so they can be indexed directly, like `ALT_DIGITS[0]`.
+const EMPTY_WHEN_MISSING: &[(&str, &str)] = &[("LC_TIME", "ALT_DIGITS")];
+
+/// The items that are part of the `LocaleData` trait.
+const LOCALE_DATA_FIELDS: &[(&str, &str)] = &[
+    ("LC_TIME", "ABDAY"),
+    ("LC_TIME", "DAY"),
+    ("LC_TIME", "ABMON"),
+    ("LC_TIME", "MON"),
+    ("LC_TIME", "AM_PM"),
+    ("LC_TIME", "D_FMT"),
+    ("LC_TIME", "T_FMT"),
+    ("LC_TIME", "D_T_FMT"),
+    ("LC_NUMERIC", "DECIMAL_POINT"),
+    ("LC_NUMERIC", "THOUSANDS_SEP"),
+    ("LC_MONETARY", "CURRENCY_SYMBOL"),
+    ("LC_MONETARY", "INT_CURR_SYMBOL"),
+    ("LC_MESSAGES", "YESEXPR"),
+    ("LC_MESSAGES", "NOEXPR"),
+];
+
+/// Replace the categories that are identical to the same category of another locale by a
+/// re-export of the first one, in the same way as a `copy`.
+fn dedup_categories(
+    by_language: &mut BTreeMap<Lang, BTreeMap<Key, Category>>,
+    normalized_langs: &BTreeMap<Lang, String>,
+) {
+    let mut first = HashMap::<(&Key, &BTreeMap<Field, Value>), &Lang>::new();
+    let mut links = Vec::new();
+
+    for (lang, categories) in by_language.iter() {
+        for (category_name, category) in categories.iter() {
+            if let Category::Fields(fields) = category {
+                let first_lang = *first.entry((category_name, fields)).or_insert(lang);
+                if first_lang != lang {
+                    links.push((lang.clone(), category_name.clone(), first_lang.clone()));
+                }
+            }
+        }
+    }
+
+    for (lang, category_name, first_lang) in links {
+        by_language.get_mut(&lang).unwrap().insert(
+            category_name,
+            Category::Link(normalized_langs[&first_lang].clone()),
+        );
+    }
+}
+
 impl std::fmt::Display for CodeGenerator {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let mut f = CodeFormatter::new(f, "    ");
@@ -654,6 +4497,8 @@ struct Meta {
     optional: bool,
     container_ty: ContainerType,
     ty: Option<Type>,
+    /// The length of an array that has the same length in all the locales, see `FIXED_LENGTHS`.
+    fixed_len: Option<usize>,
 }
 
 impl Meta {
@@ -662,6 +4507,7 @@ impl Meta {
             optional: false,
             container_ty: ContainerType::Singleton,
             ty: None,
+            fixed_len: None,
         }
     }
 
@@ -681,6 +4527,19 @@ impl Meta {
         }
     }
 
+    /// The variant of `helpers::Item` for an item of this type.
+    fn item_variant(&self) -> &'static str {
+        match (&self.ty, self.container_ty) {
+            (Some(Type::String), ContainerType::Singleton) => "Str",
+            (Some(Type::Integer), ContainerType::Singleton) => "Int",
+            (Some(Type::String), ContainerType::Array) => "StrSlice",
+            (Some(Type::Integer), ContainerType::Array) => "IntSlice",
+            (Some(Type::String), ContainerType::Array2D) => "StrSlice2d",
+            (Some(Type::Integer), ContainerType::Array2D) => "IntSlice2d",
+            (None, _) => unreachable!(),
+        }
+    }
+
     fn make_optional(&mut self) {
         self.optional = true;
     }
@@ -731,3 +4590,408 @@ impl ContainerType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+
+    fn generate(input: &str) -> String {
+        let mut objects = HashMap::new();
+        objects.insert("xx_XX".to_string(), parser::parse(input).unwrap());
+        let output = CodeGenerator::new(objects).to_string();
+        syn::parse_file(&output).expect("the generated code is not valid Rust");
+        output
+    }
+
+    /// Find the constant `name` in `items` or in their modules.
+    fn find_const<'a>(items: &'a [syn::Item], name: &str) -> Option<&'a syn::ItemConst> {
+        items.iter().find_map(|item| match item {
+            syn::Item::Const(x) if x.ident == name => Some(x),
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => find_const(items, name),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn decode_group_sizes() {
+        let decode = |x: &[&str]| group_sizes(&x.iter().map(|x| x.to_string()).collect::<Vec<_>>());
+        assert_eq!(decode(&["3", "-1"]), ["Repeat(3)", "NoMore"]);
+        assert_eq!(decode(&["3", "2"]), ["Repeat(3)", "Repeat(2)"]);
+        assert_eq!(decode(&["3", "0"]), ["Repeat(3)"]);
+        assert_eq!(decode(&["3", "0", "2"]), ["Repeat(3)"]);
+        assert_eq!(decode(&["4", "127"]), ["Repeat(4)", "NoMore"]);
+        assert_eq!(decode(&["0", "0"]), ["NoMore"]);
+        assert_eq!(decode(&["-1"]), ["NoMore"]);
+    }
+
+    #[test]
+    fn mon_grouping_falls_back_to_grouping() {
+        // `mon_grouping` missing, and without a value
+        for mon_grouping in &["", "mon_grouping\n"] {
+            let output = generate(&format!(
+                "LC_MONETARY\nmon_thousands_sep \".\"\n{}END LC_MONETARY\n\
+                 LC_NUMERIC\ngrouping 3;2\nEND LC_NUMERIC\n\
+                 LC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n",
+                mon_grouping,
+            ));
+            let output: String = output.split_whitespace().collect();
+
+            assert!(
+                output.contains("pubconstfntyped_mon_grouping()->&'static[crate::GroupSize]{&[]}")
+            );
+            assert!(output.contains(
+                "constfnnumeric_grouping()->&'static[crate::GroupSize]{\
+                 &[crate::GroupSize::Repeat(3),crate::GroupSize::Repeat(2)]}"
+            ));
+            assert!(output.contains("}elseiftyped_mon_grouping().is_empty(){numeric_grouping()}"));
+            // the sample amount is grouped the same way
+            assert!(output.contains("grouping:effective_grouping(),"));
+        }
+    }
+
+    #[test]
+    fn decode_era() {
+        let (key, literal) = parse_era("+:1:-0001/12/31:-*:BC:%EC %Ey");
+        assert_eq!(key, (-1, 0, 0, 0));
+        assert_eq!(
+            literal,
+            "crate::Era { direction: '+', offset: 1, \
+             start: crate::EraDate::Date { year: -1, month: 12, day: 31 }, \
+             end: crate::EraDate::MinusInfinity, name: \"BC\", format: \"%EC %Ey\" }"
+        );
+        assert_eq!(parse_era("+:2:2020/01/01:+*:a:b").0, (0, 2020, 1, 1));
+    }
+
+    #[test]
+    fn compile_response_exprs() {
+        let compile = |x: &str| {
+            let (first_chars, words, whole) = compile_response_expr(x);
+            (first_chars, words.join(","), whole)
+        };
+        assert_eq!(
+            compile("^[+1yY]"),
+            ("+1yY".to_string(), String::new(), false)
+        );
+        assert_eq!(
+            compile("^([+1yY]|ᎥᎥ)"),
+            ("+1yY".to_string(), "ᎥᎥ".to_string(), false)
+        );
+        assert_eq!(
+            compile("^([-0Nn]|[Нн][Іі])$"),
+            ("-0Nn".to_string(), "НІ,Ні,нІ,ні".to_string(), true)
+        );
+        assert_eq!(compile("^(y|[Тт]а[Кк]?)$").1, "Та,ТаК,Так,та,таК,так");
+    }
+
+    #[test]
+    fn classify_grouping() {
+        let style =
+            |x: &[&str]| grouping_style(&x.iter().map(|x| x.to_string()).collect::<Vec<_>>());
+        assert_eq!(style(&["3", "3"]), "Western");
+        assert_eq!(style(&["3", "0"]), "Western");
+        assert_eq!(style(&["3", "2"]), "Indian");
+        assert_eq!(style(&["3", "2", "2"]), "Indian");
+        assert_eq!(style(&["-1"]), "None");
+        assert_eq!(style(&[]), "None");
+        assert_eq!(style(&["3", "-1"]), "Custom");
+        assert_eq!(style(&["4"]), "Custom");
+    }
+
+    #[test]
+    fn source_order_is_preserved() {
+        let output = generate(
+            r#"comment_char %
+escape_char /
+
+LC_IDENTIFICATION
+title "Test"
+category "z:2000";LC_TIME
+category "a:2000";LC_NUMERIC
+category "m:2000";LC_ADDRESS
+END LC_IDENTIFICATION
+
+LC_TIME
+mon "Zero";"One";/
+    "Two"
+abmon "Z";"O";"T"
+END LC_TIME
+"#,
+        );
+
+        let z = output.find(r#"&["z:2000", "LC_TIME"],"#).unwrap();
+        let a = output.find(r#"&["a:2000", "LC_NUMERIC"],"#).unwrap();
+        let m = output.find(r#"&["m:2000", "LC_ADDRESS"],"#).unwrap();
+        assert!(z < a && a < m);
+        assert!(output.contains(r#"pub const MON: &[&str] = &["Zero", "One", "Two"];"#));
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        let output = generate(
+            r#"comment_char %
+escape_char /
+
+LC_TIME
+abmon "janv.";"f<U00E9>vr"
+END LC_TIME
+"#,
+        );
+
+        assert!(output.contains(r#"pub const ABMON: &[&str] = &["janv.", "févr"];"#));
+    }
+
+    #[test]
+    fn sorted_eras() {
+        let input = r#"comment_char %
+escape_char /
+
+LC_TIME
+era "+:2:2020//01//01:+*:C:%EC";/
+    "+:1:2019//05//01:2019//12//31:B:%EC";/
+    "-:1:-0001//12//31:-*:A:%EC"
+END LC_TIME
+"#;
+        let positions = |output: &str| {
+            let era = &output[output.find("pub const ERA: &[&str] = &[").unwrap()..];
+            let era = &era[..era.find("];").unwrap()];
+            [
+                era.find(r#""-:1:-0001/12/31:-*:A:%EC""#).unwrap(),
+                era.find(r#""+:1:2019/05/01:2019/12/31:B:%EC""#).unwrap(),
+                era.find(r#""+:2:2020/01/01:+*:C:%EC""#).unwrap(),
+            ]
+        };
+
+        // by default `ERA` keeps the order of the source, only `ERA_ENTRIES` is sorted
+        let output = generate(input);
+        let [a, b, c] = positions(&output);
+        assert!(c < b && b < a);
+        let entries = &output[output.find("pub const ERA_ENTRIES").unwrap()..];
+        let a = entries.find(r#"name: "A""#).unwrap();
+        let b = entries.find(r#"name: "B""#).unwrap();
+        let c = entries.find(r#"name: "C""#).unwrap();
+        assert!(a < b && b < c);
+
+        let mut objects = HashMap::new();
+        objects.insert("xx_XX".to_string(), parser::parse(input).unwrap());
+        let output = CodeGenerator::with_sorted_rows(objects, &[ERA_BY_START]).to_string();
+        let [a, b, c] = positions(&output);
+        assert!(a < b && b < c);
+    }
+
+    #[test]
+    fn sorted_rows_of_several_lines() {
+        let input = "LC_IDENTIFICATION\n\
+                     category \"z:2000\";LC_TIME\n\
+                     category \"a:2000\";LC_NUMERIC\n\
+                     END LC_IDENTIFICATION\n\
+                     LC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n";
+        let mut objects = HashMap::new();
+        objects.insert("xx_XX".to_string(), parser::parse(input).unwrap());
+        let by_name = SortedRows {
+            category: "LC_IDENTIFICATION",
+            item: "CATEGORY",
+            compare: |a, b| a.cmp(b),
+        };
+        let output = CodeGenerator::with_sorted_rows(objects, &[by_name]).to_string();
+
+        let z = output.find(r#"&["z:2000", "LC_TIME"],"#).unwrap();
+        let a = output.find(r#"&["a:2000", "LC_NUMERIC"],"#).unwrap();
+        assert!(a < z);
+    }
+
+    #[test]
+    fn fixed_lengths() {
+        let output = generate(
+            r#"comment_char %
+escape_char /
+
+LC_TIME
+day "1";"2";"3";"4";"5";"6";"7"
+abday "1";"2";"3";"4";"5";"6"
+END LC_TIME
+"#,
+        );
+
+        assert!(output.contains("pub const DAY: &[&str; 7] = "));
+        // the wrong number of days is kept as a slice
+        assert!(output.contains("pub const ABDAY: &[&str] = "));
+
+        // one locale with the wrong number of months makes the item a slice in all locales
+        let mut objects = HashMap::new();
+        for (lang, mon) in [
+            (
+                "xx_XX",
+                r#""1";"2";"3";"4";"5";"6";"7";"8";"9";"10";"11";"12""#,
+            ),
+            ("yy_YY", r#""1";"2";"3";"4";"5";"6";"7";"8";"9";"10";"11""#),
+        ] {
+            let input = format!("LC_TIME\nmon {}\nEND LC_TIME\n", mon);
+            objects.insert(lang.to_string(), parser::parse(&input).unwrap());
+        }
+        let output = CodeGenerator::new(objects).to_string();
+
+        assert_eq!(output.matches("pub const MON: &[&str] = ").count(), 2);
+        assert!(!output.contains("pub const MON: &[&str; 12]"));
+    }
+
+    #[test]
+    fn empty_when_missing() {
+        let mut objects = HashMap::new();
+        let with = "LC_TIME\nalt_digits \"0\";\"1\"\nEND LC_TIME\n";
+        let without = "LC_TIME\nday \"1\"\nEND LC_TIME\n";
+        objects.insert("xx_XX".to_string(), parser::parse(with).unwrap());
+        objects.insert("yy_YY".to_string(), parser::parse(without).unwrap());
+        let output = CodeGenerator::new(objects).to_string();
+
+        assert!(output.contains(r#"pub const ALT_DIGITS: &[&str] = &["0", "1"];"#));
+        assert!(output.contains("pub const ALT_DIGITS: &[&str] = &[];"));
+        assert!(!output.contains("pub const ALT_DIGITS: Option"));
+    }
+
+    #[test]
+    fn shared_slices() {
+        let mut objects = HashMap::new();
+        for (lang, decimal_point, grouping) in [
+            ("xx_XX", ".", "3;3"),
+            ("yy_YY", ",", "3;3"),
+            ("zz_ZZ", ".", "3;2"),
+        ] {
+            let input = format!(
+                "LC_NUMERIC\ndecimal_point \"{}\"\ngrouping {}\nEND LC_NUMERIC\n\
+                 LC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n",
+                decimal_point, grouping,
+            );
+            objects.insert(lang.to_string(), parser::parse(&input).unwrap());
+        }
+        let output = CodeGenerator::new(objects).to_string();
+
+        let line = output
+            .lines()
+            .find(|x| x.contains("pub(crate) const SLICE_") && x.ends_with(": &[i64] = &[3, 3];"))
+            .unwrap();
+        let name = line.trim_start().split(':').next().unwrap();
+        let name = name.trim_start_matches("pub(crate) const ");
+        // the two locales refer to the same constant instead of having their own literal
+        let reference = format!("pub const GROUPING: &[i64] = crate::slices::{};", name);
+        assert_eq!(output.matches(&reference).count(), 2);
+        assert!(!output.contains("pub const GROUPING: &[i64] = &[3, 3];"));
+        // a slice used once is written in place
+        assert!(output.contains("pub const GROUPING: &[i64] = &[3, 2];"));
+    }
+
+    #[test]
+    fn unknown_copy() {
+        let mut objects = HashMap::new();
+        let input = "LC_TIME\ncopy \"does_not_exist\"\nEND LC_TIME\n\
+                     LC_NUMERIC\ndecimal_point \",\"\nEND LC_NUMERIC\n";
+        objects.insert("xx_XX".to_string(), parser::parse(input).unwrap());
+        let input = "LC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n";
+        objects.insert("yy_YY".to_string(), parser::parse(input).unwrap());
+        let generator = CodeGenerator::new(objects);
+
+        assert_eq!(
+            generator.copy_errors(),
+            [CopyError::UnknownLocale {
+                lang: "xx_XX".to_string(),
+                category: "LC_TIME".to_string(),
+                target: "does_not_exist".to_string(),
+            }],
+        );
+        // the category is generated without items
+        let output = generator.to_string();
+        assert!(output.contains("pub mod xx_XX {"));
+        assert!(output.contains("pub const D_FMT: Option<&str> = None;"));
+        assert!(output.contains(r#"pub const D_FMT: Option<&str> = Some("%d");"#));
+    }
+
+    #[test]
+    fn copy_chains() {
+        let copy = |x: &str| format!("LC_TIME\ncopy \"{}\"\nEND LC_TIME\n", x);
+        let mut objects = HashMap::new();
+        for (lang, input) in [
+            ("aa_AA", copy("bb_BB")),
+            ("bb_BB", copy("cc_CC")),
+            ("cc_CC", "LC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n".to_string()),
+            ("xx_XX", copy("yy_YY")),
+            ("yy_YY", copy("xx_XX")),
+        ] {
+            objects.insert(lang.to_string(), parser::parse(&input).unwrap());
+        }
+        let generator = CodeGenerator::new(objects);
+
+        // the cycle is broken at its first locale
+        assert_eq!(
+            generator.copy_errors(),
+            [CopyError::Cycle {
+                lang: "xx_XX".to_string(),
+                category: "LC_TIME".to_string(),
+                chain: vec![
+                    "xx_XX".to_string(),
+                    "yy_YY".to_string(),
+                    "xx_XX".to_string()
+                ],
+            }],
+        );
+        assert_eq!(
+            generator.copy_errors()[0].to_string(),
+            "LC_TIME of xx_XX copies itself: xx_XX -> yy_YY -> xx_XX",
+        );
+        assert_eq!(generator.link_root("aa_AA", "LC_TIME"), "cc_CC");
+        assert_eq!(generator.link_root("yy_YY", "LC_TIME"), "xx_XX");
+
+        let output = generator.to_string();
+        assert!(output.contains("pub use crate::shared::cc_CC::LC_TIME;"));
+        assert!(output.contains(r#"pub const D_FMT: Option<&str> = Some("%d");"#));
+        assert!(output.contains("pub use crate::shared::xx_XX::LC_TIME;"));
+        assert!(output.contains("pub const D_FMT: Option<&str> = None;"));
+    }
+
+    #[test]
+    fn negative_integers() {
+        let mut objects = HashMap::new();
+        for (lang, grouping) in [("xx_XX", "-1"), ("yy_YY", "3;-1"), ("zz_ZZ", "3;2")] {
+            let input = format!(
+                "LC_NUMERIC\ngrouping {}\nEND LC_NUMERIC\nLC_TIME\nd_fmt \"%d\"\nEND LC_TIME\n",
+                grouping,
+            );
+            objects.insert(lang.to_string(), parser::parse(&input).unwrap());
+        }
+        let output = CodeGenerator::new(objects).to_string();
+
+        // a single `-1` is a slice like the groupings of the other locales
+        assert!(output.contains("pub const GROUPING: &[i64] = &[-1];"));
+        assert!(output.contains("pub const GROUPING: &[i64] = &[3, -1];"));
+        assert!(output.contains("pub const GROUPING: &[i64] = &[3, 2];"));
+        assert!(output.contains("crate::GroupSize::Repeat(3), crate::GroupSize::NoMore"));
+    }
+
+    #[test]
+    fn mixed_types() {
+        let output = generate(
+            r#"comment_char %
+escape_char /
+
+LC_TIME
+mixed "a";1
+mixed_rows "b";2
+mixed_rows 3;4
+END LC_TIME
+"#,
+        );
+
+        let file = syn::parse_file(&output).unwrap();
+        let tokens = |x: &dyn ToTokens| x.to_token_stream().to_string();
+
+        let mixed = find_const(&file.items, "MIXED").unwrap();
+        assert_eq!(tokens(&mixed.ty), "& [& str]");
+        assert_eq!(tokens(&mixed.expr), r#"& ["a" , "1"]"#);
+
+        let rows = find_const(&file.items, "MIXED_ROWS").unwrap();
+        assert_eq!(tokens(&rows.ty), "& [& [& str]]");
+        assert_eq!(tokens(&rows.expr), r#"& [& ["b" , "2"] , & ["3" , "4"] ,]"#);
+    }
+}
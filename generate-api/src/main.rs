@@ -1,15 +1,17 @@
 pub mod generator;
 pub mod parser;
+pub mod tables;
 
 use crate::parser::{Object, Value};
 use anyhow::{bail, Result};
 use cargo_metadata::MetadataCommand;
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::io;
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
 fn main() -> Result<()> {
     let metadata = MetadataCommand::new().exec()?;
@@ -19,7 +21,7 @@ fn main() -> Result<()> {
     let locales_path = metadata.workspace_root.join("localedata").join("locales");
     let mut locales = HashMap::new();
 
-    for entry in fs::read_dir(locales_path)? {
+    for entry in fs::read_dir(&locales_path)? {
         let entry = entry?;
         let file_name = entry.file_name();
         let lang = file_name.to_str().unwrap();
@@ -34,18 +36,32 @@ fn main() -> Result<()> {
         if let Ok(input) = std::fs::read_to_string(&path) {
             eprintln!("{}", path.display());
             let mut objects = parser::parse(&input)?;
+            if lang == "POSIX" {
+                add_posix_defaults(&mut objects);
+            }
             validate_and_fix(&mut objects);
+            validate_month_names(lang, &objects)?;
             locales.insert(lang.to_string(), objects);
         }
     }
 
+    inline_copies_of_sources(&mut locales, &locales_path)?;
+
     let lib_file = metadata.workspace_root.join("src").join("lib.rs");
+    let manifest_file = metadata.workspace_root.join("Cargo.toml");
+    let generator = generator::CodeGenerator::new(locales);
+    for error in generator.copy_errors() {
+        eprintln!("warning: {}", error);
+    }
+
+    let manifest = fs::read_to_string(&manifest_file)?;
+    let expected_manifest = replace_features(&manifest, &generator.cargo_features())?;
 
     if env::var("CHECK").is_ok() {
         eprintln!("Calculating checksum...");
         let mut f = Sha256::default();
 
-        write!(f, "{}", generator::CodeGenerator::new(locales))?;
+        write!(f, "{}", generator)?;
 
         let expected = f.finalize();
         eprintln!("expected: {:x}", expected);
@@ -60,18 +76,139 @@ fn main() -> Result<()> {
                 "lib.rs file has been modified! Please run `cargo run -p generate-api --release`",
             );
         }
+        if expected_manifest != manifest {
+            bail!(
+                "the features of Cargo.toml have been modified! Please run `cargo run -p generate-api --release`",
+            );
+        }
     } else {
         eprintln!("Writing to file `{}`...", lib_file.display());
         let mut f = BufWriter::new(fs::File::create(&lib_file)?);
-        write!(f, "{}", generator::CodeGenerator::new(locales))?;
+        write!(f, "{}", generator)?;
+
+        if expected_manifest != manifest {
+            eprintln!("Writing to file `{}`...", manifest_file.display());
+            fs::write(&manifest_file, expected_manifest)?;
+        }
     }
 
     Ok(())
 }
 
+/// Replace the generated features between the marker comments of `Cargo.toml` by `features`.
+fn replace_features(manifest: &str, features: &str) -> Result<String> {
+    const BEGIN: &str = "# BEGIN generated by generate-api\n";
+    const END: &str = "# END generated by generate-api\n";
+
+    let (start, end) = match (manifest.find(BEGIN), manifest.find(END)) {
+        (Some(start), Some(end)) if start < end => (start + BEGIN.len(), end),
+        _ => bail!("Cargo.toml has no generated features"),
+    };
+    Ok([&manifest[..start], features, &manifest[end..]].concat())
+}
+
 fn validate_and_fix(objects: &mut [Object]) {
     validate_and_fix_t_fmt_ampm(objects);
     validate_and_fix_d_t_fmt(objects);
+    validate_and_fix_empty_items(objects);
+}
+
+/// Check that the month names of `LC_TIME`, including the optional alternative month names
+/// `ALT_MON` and `AB_ALT_MON`, have 12 entries when present.
+fn validate_month_names(lang: &str, objects: &[Object]) -> Result<()> {
+    for object in objects.iter() {
+        if object.name != "LC_TIME" {
+            continue;
+        }
+        for (key, value) in object.values.iter() {
+            if let "mon" | "abmon" | "alt_mon" | "ab_alt_mon" = key.as_str() {
+                if value.len() != 12 {
+                    bail!(
+                        "{}: `{}` has {} entries instead of 12",
+                        lang,
+                        key,
+                        value.len()
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Add the categories of the built-in `C` locale of glibc that the `POSIX` file doesn't define:
+/// `LC_PAPER` with A4 paper, `LC_MEASUREMENT` with the metric system, and `LC_TELEPHONE` and
+/// `LC_NAME` with only a format.
+///
+/// All other locales define these categories, so with them the items are not optional.
+fn add_posix_defaults(objects: &mut Vec<Object>) {
+    let defaults = [
+        (
+            "LC_PAPER",
+            vec![
+                ("height", Value::Integer(297)),
+                ("width", Value::Integer(210)),
+            ],
+        ),
+        ("LC_MEASUREMENT", vec![("measurement", Value::Integer(1))]),
+        (
+            "LC_TELEPHONE",
+            vec![("tel_int_fmt", Value::String("+%c %a %l".to_string()))],
+        ),
+        (
+            "LC_NAME",
+            vec![("name_fmt", Value::String("%p%t%g%t%m%t%f".to_string()))],
+        ),
+    ];
+    for (name, values) in defaults {
+        if objects.iter().any(|x| x.name == name) {
+            continue;
+        }
+        objects.push(Object {
+            name: name.to_string(),
+            values: values
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), vec![value]))
+                .collect(),
+        });
+    }
+}
+
+/// Replace a category that only copies the same category from a file that is not a locale, like
+/// `copy "i18n"`, by the items of that category.
+///
+/// The generator turns a `copy` into a re-export of the category of another locale, which is not
+/// possible for these files. `LC_COLLATE` and `LC_CTYPE` are not generated and left alone.
+fn inline_copies_of_sources(
+    locales: &mut HashMap<String, Vec<Object>>,
+    locales_path: &Path,
+) -> Result<()> {
+    let mut sources = HashMap::<String, Vec<Object>>::new();
+    let names: HashSet<String> = locales.keys().cloned().collect();
+
+    for objects in locales.values_mut() {
+        for object in objects.iter_mut() {
+            if object.name == "LC_COLLATE" || object.name == "LC_CTYPE" {
+                continue;
+            }
+            let source = match object.values.as_slice() {
+                [(key, value)] if key == "copy" => match value.as_slice() {
+                    [Value::String(x)] if !names.contains(x) => x.clone(),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            if !sources.contains_key(&source) {
+                let input = fs::read_to_string(locales_path.join(&source))?;
+                sources.insert(source.clone(), parser::parse(&input)?);
+            }
+            match sources[&source].iter().find(|x| x.name == object.name) {
+                Some(x) => object.values = x.values.clone(),
+                None => bail!("`{}` has no category `{}`", source, object.name),
+            }
+        }
+    }
+    Ok(())
 }
 
 /// Add a `T_FMT_AMPM` item if it is missing or empty.
@@ -117,6 +254,46 @@ fn validate_and_fix_t_fmt_ampm(objects: &mut [Object]) {
     }
 }
 
+/// Add the missing string items of `LC_TELEPHONE` and `LC_NAME` with an empty value, like
+/// `localedef` does.
+///
+/// Many locales have no `TEL_DOM_FMT` or `NAME_GEN`, and `eo` has no `INT_PREFIX`.
+fn validate_and_fix_empty_items(objects: &mut [Object]) {
+    let items: [(&str, &[&str]); 2] = [
+        (
+            "LC_TELEPHONE",
+            &["tel_int_fmt", "tel_dom_fmt", "int_select", "int_prefix"],
+        ),
+        (
+            "LC_NAME",
+            &[
+                "name_fmt",
+                "name_gen",
+                "name_mr",
+                "name_mrs",
+                "name_miss",
+                "name_ms",
+            ],
+        ),
+    ];
+    for object in objects.iter_mut() {
+        if object.values.iter().any(|(key, _)| key == "copy") {
+            continue;
+        }
+        let keys = match items.iter().find(|(name, _)| *name == object.name) {
+            Some((_, keys)) => keys,
+            None => continue,
+        };
+        for key in keys.iter() {
+            if !object.values.iter().any(|(x, _)| x == key) {
+                object
+                    .values
+                    .push((key.to_string(), vec![Value::String(String::new())]));
+            }
+        }
+    }
+}
+
 /// In some locales `D_T_FMT` refers to other items:
 /// to `D_FMT` with `%x`, `T_FMT` with `%X`, and/or `T_FMT_AMPM` with `%r`.
 /// In the same way `T_FMT` can refer `T_FMT_AMPM` with `%r`.
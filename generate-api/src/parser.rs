@@ -3,32 +3,22 @@ use nom::{
     branch::alt,
     bytes::complete::{tag, take, take_while, take_while1},
     character::complete::{
-        alpha1, anychar, char, hex_digit1, multispace0, multispace1, not_line_ending, one_of,
-        space1,
+        alpha1, anychar, char, multispace0, multispace1, not_line_ending, one_of, space1,
     },
-    combinator::{all_consuming, cut, map, map_opt, map_parser, map_res, opt, verify},
+    combinator::{all_consuming, cut, map, map_parser, map_res, opt, verify},
     error::{context, ErrorKind, ParseError},
     multi::{fold_many0, fold_many1, many0, many1, separated_list},
     sequence::{preceded, separated_pair, terminated},
     IResult,
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     Raw(String),
     String(String),
     Integer(i64),
 }
 
-impl From<&Value> for u8 {
-    fn from(x: &Value) -> u8 {
-        match x {
-            Value::Raw(_) | Value::String(_) => 0,
-            Value::Integer(_) => 1,
-        }
-    }
-}
-
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -133,20 +123,61 @@ fn string<'a, E: ParseError<&'a str>>(
     )(i)
 }
 
+/// Replace the escapes of code points like `<U00E9>` by their character.
+///
+/// An escape that is not 4 or 8 hexadecimal digits, or that is not a valid `char` (like the
+/// surrogate `<UD800>`), is an error.
+///
+/// Most strings of the locale files are written entirely with escapes, so this is a hand-written
+/// loop that fills one `String` instead of a combinator allocating a `String` per escape.
 fn unescape_unicode<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, String, E> {
-    map(
-        many0(alt((
-            map(take_while1(|c| c != '<'), |x: &str| x.to_string()),
-            map_opt(
-                map_res(
-                    preceded(tag("<U"), terminated(hex_digit1, char('>'))),
-                    |x: &str| u32::from_str_radix(x, 16),
-                ),
-                |x: u32| std::char::from_u32(x).map(|x| x.to_string()),
-            ),
-        ))),
-        |x: Vec<String>| x.join(""),
-    )(i)
+    if !i.contains('<') {
+        return Ok(("", i.to_string()));
+    }
+
+    let mut out = String::with_capacity(i.len());
+    let mut rest = i;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if !rest.starts_with("<U") {
+            out.push('<');
+            rest = &rest[1..];
+            continue;
+        }
+        let digits = &rest[2..];
+        let len = digits
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(digits.len());
+        if len != 4 && len != 8 {
+            return Err(nom::Err::Failure(E::from_error_kind(
+                digits,
+                ErrorKind::Verify,
+            )));
+        }
+        if !digits[len..].starts_with('>') {
+            return Err(nom::Err::Failure(E::from_error_kind(
+                &digits[len..],
+                ErrorKind::Char,
+            )));
+        }
+        match u32::from_str_radix(&digits[..len], 16)
+            .ok()
+            .and_then(std::char::from_u32)
+        {
+            Some(c) => out.push(c),
+            None => {
+                return Err(nom::Err::Failure(E::from_error_kind(
+                    digits,
+                    ErrorKind::MapOpt,
+                )))
+            }
+        }
+        rest = &digits[len + 1..];
+    }
+    out.push_str(rest);
+
+    Ok(("", out))
 }
 
 fn parse_special_chars<'a, E: ParseError<&'a str>>(
@@ -292,7 +323,7 @@ pub fn parse(input: &str) -> Result<Vec<Object>> {
 pub fn parse_lang(input: &str) -> Result<(&str, Option<&str>, Option<&str>)> {
     fn inner_parser<'a, E: ParseError<&'a str>>(
         i: &'a str,
-    ) -> IResult<&'a str, (&str, Option<&str>, Option<&str>), E> {
+    ) -> IResult<&'a str, (&'a str, Option<&'a str>, Option<&'a str>), E> {
         let (i, lang) = verify(alpha1, |x: &str| x != "translit")(i)?;
         let (i, country) = opt(preceded(char('_'), alpha1))(i)?;
         let (i, variant) = all_consuming(opt(preceded(char('@'), alpha1)))(i)?;
@@ -304,3 +335,173 @@ pub fn parse_lang(input: &str) -> Result<(&str, Option<&str>, Option<&str>)> {
         Err(err) => bail!("could not parse lang: {}", err),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unicode_escapes() {
+        let objects =
+            parse("LC_NUMERIC\ndecimal_point \",\"\nthousands_sep \"<U00A0>\"\nEND LC_NUMERIC\n")
+                .unwrap();
+
+        assert_eq!(
+            objects[0].values[1],
+            (
+                "thousands_sep".to_string(),
+                vec![Value::String("\u{a0}".to_string())],
+            ),
+        );
+    }
+
+    #[test]
+    fn unicode_escapes_8_digits() {
+        let objects =
+            parse("LC_TIME\nabmon \"<U00E9>vr\";\"<U0001F600>\";\"a<b\"\nEND LC_TIME\n").unwrap();
+
+        assert_eq!(
+            objects[0].values[0].1,
+            [
+                Value::String("\u{e9}vr".to_string()),
+                Value::String("\u{1f600}".to_string()),
+                Value::String("a<b".to_string()),
+            ],
+        );
+    }
+
+    /// The combinator `unescape_unicode` was before it got a hand-written loop, to check that the
+    /// output didn't change.
+    fn unescape_unicode_combinator(i: &str) -> IResult<&str, String, (&str, ErrorKind)> {
+        use nom::{character::complete::hex_digit1, combinator::map_opt};
+
+        all_consuming(map(
+            many0(alt((
+                map(take_while1(|c| c != '<'), |x: &str| x.to_string()),
+                preceded(
+                    tag("<U"),
+                    cut(map_opt(
+                        map_res(
+                            terminated(
+                                verify(hex_digit1, |x: &str| x.len() == 4 || x.len() == 8),
+                                char('>'),
+                            ),
+                            |x: &str| u32::from_str_radix(x, 16),
+                        ),
+                        |x: u32| std::char::from_u32(x).map(|x| x.to_string()),
+                    )),
+                ),
+                map(tag("<"), |x: &str| x.to_string()),
+            ))),
+            |x: Vec<String>| x.join(""),
+        ))(i)
+    }
+
+    fn locale_file(name: &str) -> String {
+        let path = format!(
+            "{}/../localedata/locales/{}",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn unescape_unicode_like_combinator() {
+        let mut inputs = vec![
+            "",
+            "abc",
+            "<",
+            "a<b",
+            "<U",
+            "<U>",
+            "<U00E9>",
+            "<U00e9>x",
+            "<U0001F600>",
+            "<U0E9>",
+            "<UD800>",
+            "<U110000>",
+            "<U00E9",
+            "<U00E9x>",
+            "<<U0041>>",
+            "<U0041><U0042>",
+        ];
+        let files = ["ja_JP", "fa_IR", "de_DE", "i18n_ctype"].map(locale_file);
+        inputs.extend(files.iter().flat_map(|x| x.lines()));
+
+        for input in inputs {
+            assert_eq!(
+                unescape_unicode::<(&str, ErrorKind)>(input).ok(),
+                unescape_unicode_combinator(input).ok(),
+                "{:?}",
+                input,
+            );
+        }
+    }
+
+    /// Compare the time to unescape and to parse a large locale file, with
+    /// `cargo test --release -p generate-api bench_ -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_parse_large_locale() {
+        use std::time::Instant;
+
+        let input = locale_file("ja_JP");
+        let lines: Vec<_> = input.lines().collect();
+        let count = 50;
+
+        let start = Instant::now();
+        for _ in 0..count {
+            for line in lines.iter() {
+                let _ = unescape_unicode_combinator(line);
+            }
+        }
+        let combinator = start.elapsed() / count;
+
+        let start = Instant::now();
+        for _ in 0..count {
+            for line in lines.iter() {
+                let _ = unescape_unicode::<(&str, ErrorKind)>(line);
+            }
+        }
+        let loop_ = start.elapsed() / count;
+
+        let start = Instant::now();
+        for _ in 0..count {
+            parse(&input).unwrap();
+        }
+        let parse = start.elapsed() / count;
+
+        eprintln!(
+            "ja_JP ({} bytes): unescape {:?} with the combinator, {:?} with the loop, parse {:?}",
+            input.len(),
+            combinator,
+            loop_,
+            parse,
+        );
+    }
+
+    #[test]
+    fn negative_integers() {
+        let objects = parse("LC_NUMERIC\ngrouping -1\nEND LC_NUMERIC\n").unwrap();
+        assert_eq!(objects[0].values[0].1, [Value::Integer(-1)]);
+
+        let objects = parse("LC_NUMERIC\ngrouping 3;-1;0\nEND LC_NUMERIC\n").unwrap();
+        assert_eq!(
+            objects[0].values[0].1,
+            [Value::Integer(3), Value::Integer(-1), Value::Integer(0)],
+        );
+        assert_eq!(objects[0].values[0].1[1].to_string(), "-1");
+
+        // not a number
+        let objects = parse("LC_NUMERIC\ngrouping -\nEND LC_NUMERIC\n").unwrap();
+        assert_eq!(objects[0].values[0].1, [Value::Raw("-".to_string())]);
+    }
+
+    #[test]
+    fn invalid_unicode_escapes() {
+        assert!(parse("LC_TIME\nabmon \"<UD800>\"\nEND LC_TIME\n").is_err());
+        assert!(parse("LC_TIME\nabmon \"<U110000>\"\nEND LC_TIME\n").is_err());
+        assert!(parse("LC_TIME\nabmon \"<U0E9>\"\nEND LC_TIME\n").is_err());
+    }
+}
@@ -0,0 +1,433 @@
+//! Curated data that is not part of the glibc locale sources.
+
+/// ISO 15924 script codes implied by a locale modifier.
+pub const MODIFIER_SCRIPTS: &[(&str, &str)] = &[
+    ("cyrillic", "Cyrl"),
+    ("devanagari", "Deva"),
+    ("iqtelif", "Latn"),
+    ("latin", "Latn"),
+];
+
+/// ISO 15924 script codes for locales that don't use the default script of their language.
+pub const LOCALE_SCRIPTS: &[(&str, &str)] = &[
+    ("cmn_TW", "Hant"),
+    ("hak_TW", "Hant"),
+    ("lzh_TW", "Hant"),
+    ("nan_TW", "Hant"),
+    ("yue_HK", "Hant"),
+    ("zh_CN", "Hans"),
+    ("zh_HK", "Hant"),
+    ("zh_SG", "Hans"),
+    ("zh_TW", "Hant"),
+];
+
+/// ISO 15924 script codes used by default for a language, as written in the glibc locales.
+pub const LANGUAGE_SCRIPTS: &[(&str, &str)] = &[
+    ("aa", "Latn"),
+    ("af", "Latn"),
+    ("agr", "Latn"),
+    ("ak", "Latn"),
+    ("am", "Ethi"),
+    ("an", "Latn"),
+    ("anp", "Deva"),
+    ("ar", "Arab"),
+    ("as", "Beng"),
+    ("ast", "Latn"),
+    ("ayc", "Latn"),
+    ("az", "Latn"),
+    ("be", "Cyrl"),
+    ("bem", "Latn"),
+    ("ber", "Latn"),
+    ("bg", "Cyrl"),
+    ("bhb", "Deva"),
+    ("bho", "Deva"),
+    ("bi", "Latn"),
+    ("bn", "Beng"),
+    ("bo", "Tibt"),
+    ("br", "Latn"),
+    ("brx", "Deva"),
+    ("bs", "Latn"),
+    ("byn", "Ethi"),
+    ("ca", "Latn"),
+    ("ce", "Cyrl"),
+    ("chr", "Cher"),
+    ("crh", "Latn"),
+    ("cs", "Latn"),
+    ("csb", "Latn"),
+    ("cv", "Cyrl"),
+    ("cy", "Latn"),
+    ("da", "Latn"),
+    ("de", "Latn"),
+    ("doi", "Deva"),
+    ("dsb", "Latn"),
+    ("dv", "Thaa"),
+    ("dz", "Tibt"),
+    ("el", "Grek"),
+    ("en", "Latn"),
+    ("eo", "Latn"),
+    ("es", "Latn"),
+    ("et", "Latn"),
+    ("eu", "Latn"),
+    ("fa", "Arab"),
+    ("ff", "Latn"),
+    ("fi", "Latn"),
+    ("fil", "Latn"),
+    ("fo", "Latn"),
+    ("fr", "Latn"),
+    ("fur", "Latn"),
+    ("fy", "Latn"),
+    ("ga", "Latn"),
+    ("gd", "Latn"),
+    ("gez", "Ethi"),
+    ("gl", "Latn"),
+    ("gu", "Gujr"),
+    ("gv", "Latn"),
+    ("ha", "Latn"),
+    ("he", "Hebr"),
+    ("hi", "Deva"),
+    ("hif", "Latn"),
+    ("hne", "Deva"),
+    ("hr", "Latn"),
+    ("hsb", "Latn"),
+    ("ht", "Latn"),
+    ("hu", "Latn"),
+    ("hy", "Armn"),
+    ("ia", "Latn"),
+    ("id", "Latn"),
+    ("ig", "Latn"),
+    ("ik", "Latn"),
+    ("is", "Latn"),
+    ("it", "Latn"),
+    ("iu", "Cans"),
+    ("ja", "Jpan"),
+    ("ka", "Geor"),
+    ("kab", "Latn"),
+    ("kk", "Cyrl"),
+    ("kl", "Latn"),
+    ("km", "Khmr"),
+    ("kn", "Knda"),
+    ("ko", "Kore"),
+    ("kok", "Deva"),
+    ("ks", "Arab"),
+    ("ku", "Latn"),
+    ("kw", "Latn"),
+    ("ky", "Cyrl"),
+    ("lb", "Latn"),
+    ("lg", "Latn"),
+    ("li", "Latn"),
+    ("lij", "Latn"),
+    ("ln", "Latn"),
+    ("lo", "Laoo"),
+    ("lt", "Latn"),
+    ("lv", "Latn"),
+    ("mag", "Deva"),
+    ("mai", "Deva"),
+    ("mfe", "Latn"),
+    ("mg", "Latn"),
+    ("mhr", "Cyrl"),
+    ("mi", "Latn"),
+    ("miq", "Latn"),
+    ("mjw", "Latn"),
+    ("mk", "Cyrl"),
+    ("ml", "Mlym"),
+    ("mn", "Cyrl"),
+    ("mni", "Beng"),
+    ("mnw", "Mymr"),
+    ("mr", "Deva"),
+    ("ms", "Latn"),
+    ("mt", "Latn"),
+    ("my", "Mymr"),
+    ("nb", "Latn"),
+    ("nds", "Latn"),
+    ("ne", "Deva"),
+    ("nhn", "Latn"),
+    ("niu", "Latn"),
+    ("nl", "Latn"),
+    ("nn", "Latn"),
+    ("nr", "Latn"),
+    ("nso", "Latn"),
+    ("oc", "Latn"),
+    ("om", "Latn"),
+    ("or", "Orya"),
+    ("os", "Cyrl"),
+    ("pa", "Guru"),
+    ("pap", "Latn"),
+    ("pl", "Latn"),
+    ("ps", "Arab"),
+    ("pt", "Latn"),
+    ("quz", "Latn"),
+    ("raj", "Deva"),
+    ("ro", "Latn"),
+    ("ru", "Cyrl"),
+    ("rw", "Latn"),
+    ("sa", "Deva"),
+    ("sah", "Cyrl"),
+    ("sat", "Deva"),
+    ("sc", "Latn"),
+    ("sd", "Arab"),
+    ("se", "Latn"),
+    ("sgs", "Latn"),
+    ("shn", "Mymr"),
+    ("shs", "Latn"),
+    ("si", "Sinh"),
+    ("sid", "Latn"),
+    ("sk", "Latn"),
+    ("sl", "Latn"),
+    ("sm", "Latn"),
+    ("so", "Latn"),
+    ("sq", "Latn"),
+    ("sr", "Cyrl"),
+    ("ss", "Latn"),
+    ("st", "Latn"),
+    ("sv", "Latn"),
+    ("sw", "Latn"),
+    ("szl", "Latn"),
+    ("ta", "Taml"),
+    ("tcy", "Knda"),
+    ("te", "Telu"),
+    ("tg", "Cyrl"),
+    ("th", "Thai"),
+    ("the", "Deva"),
+    ("ti", "Ethi"),
+    ("tig", "Ethi"),
+    ("tk", "Latn"),
+    ("tl", "Latn"),
+    ("tn", "Latn"),
+    ("to", "Latn"),
+    ("tpi", "Latn"),
+    ("tr", "Latn"),
+    ("ts", "Latn"),
+    ("tt", "Cyrl"),
+    ("ug", "Arab"),
+    ("uk", "Cyrl"),
+    ("unm", "Latn"),
+    ("ur", "Arab"),
+    ("uz", "Latn"),
+    ("ve", "Latn"),
+    ("vi", "Latn"),
+    ("wa", "Latn"),
+    ("wae", "Latn"),
+    ("wal", "Ethi"),
+    ("wo", "Latn"),
+    ("xh", "Latn"),
+    ("yi", "Hebr"),
+    ("yo", "Latn"),
+    ("yue", "Hant"),
+    ("yuw", "Latn"),
+    ("zh", "Hans"),
+    ("zu", "Latn"),
+];
+
+/// Names of languages in a few user interface languages: `(ui_language, language, name)`.
+pub const DISPLAY_NAMES: &[(&str, &str, &str)] = &[
+    ("de", "ar", "Arabisch"),
+    ("de", "bg", "Bulgarisch"),
+    ("de", "ca", "Katalanisch"),
+    ("de", "cs", "Tschechisch"),
+    ("de", "da", "Dänisch"),
+    ("de", "de", "Deutsch"),
+    ("de", "el", "Griechisch"),
+    ("de", "en", "Englisch"),
+    ("de", "es", "Spanisch"),
+    ("de", "et", "Estnisch"),
+    ("de", "fi", "Finnisch"),
+    ("de", "fr", "Französisch"),
+    ("de", "he", "Hebräisch"),
+    ("de", "hi", "Hindi"),
+    ("de", "hr", "Kroatisch"),
+    ("de", "hu", "Ungarisch"),
+    ("de", "id", "Indonesisch"),
+    ("de", "it", "Italienisch"),
+    ("de", "ja", "Japanisch"),
+    ("de", "ko", "Koreanisch"),
+    ("de", "lt", "Litauisch"),
+    ("de", "lv", "Lettisch"),
+    ("de", "nb", "Norwegisch (Bokmål)"),
+    ("de", "nl", "Niederländisch"),
+    ("de", "pl", "Polnisch"),
+    ("de", "pt", "Portugiesisch"),
+    ("de", "ro", "Rumänisch"),
+    ("de", "ru", "Russisch"),
+    ("de", "sk", "Slowakisch"),
+    ("de", "sl", "Slowenisch"),
+    ("de", "sr", "Serbisch"),
+    ("de", "sv", "Schwedisch"),
+    ("de", "th", "Thailändisch"),
+    ("de", "tr", "Türkisch"),
+    ("de", "uk", "Ukrainisch"),
+    ("de", "vi", "Vietnamesisch"),
+    ("de", "zh", "Chinesisch"),
+    ("en", "ar", "Arabic"),
+    ("en", "bg", "Bulgarian"),
+    ("en", "ca", "Catalan"),
+    ("en", "cs", "Czech"),
+    ("en", "da", "Danish"),
+    ("en", "de", "German"),
+    ("en", "el", "Greek"),
+    ("en", "en", "English"),
+    ("en", "es", "Spanish"),
+    ("en", "et", "Estonian"),
+    ("en", "fi", "Finnish"),
+    ("en", "fr", "French"),
+    ("en", "he", "Hebrew"),
+    ("en", "hi", "Hindi"),
+    ("en", "hr", "Croatian"),
+    ("en", "hu", "Hungarian"),
+    ("en", "id", "Indonesian"),
+    ("en", "it", "Italian"),
+    ("en", "ja", "Japanese"),
+    ("en", "ko", "Korean"),
+    ("en", "lt", "Lithuanian"),
+    ("en", "lv", "Latvian"),
+    ("en", "nb", "Norwegian Bokmål"),
+    ("en", "nl", "Dutch"),
+    ("en", "pl", "Polish"),
+    ("en", "pt", "Portuguese"),
+    ("en", "ro", "Romanian"),
+    ("en", "ru", "Russian"),
+    ("en", "sk", "Slovak"),
+    ("en", "sl", "Slovenian"),
+    ("en", "sr", "Serbian"),
+    ("en", "sv", "Swedish"),
+    ("en", "th", "Thai"),
+    ("en", "tr", "Turkish"),
+    ("en", "uk", "Ukrainian"),
+    ("en", "vi", "Vietnamese"),
+    ("en", "zh", "Chinese"),
+    ("es", "ar", "árabe"),
+    ("es", "bg", "búlgaro"),
+    ("es", "ca", "catalán"),
+    ("es", "cs", "checo"),
+    ("es", "da", "danés"),
+    ("es", "de", "alemán"),
+    ("es", "el", "griego"),
+    ("es", "en", "inglés"),
+    ("es", "es", "español"),
+    ("es", "et", "estonio"),
+    ("es", "fi", "finés"),
+    ("es", "fr", "francés"),
+    ("es", "he", "hebreo"),
+    ("es", "hi", "hindi"),
+    ("es", "hr", "croata"),
+    ("es", "hu", "húngaro"),
+    ("es", "id", "indonesio"),
+    ("es", "it", "italiano"),
+    ("es", "ja", "japonés"),
+    ("es", "ko", "coreano"),
+    ("es", "lt", "lituano"),
+    ("es", "lv", "letón"),
+    ("es", "nb", "noruego bokmal"),
+    ("es", "nl", "neerlandés"),
+    ("es", "pl", "polaco"),
+    ("es", "pt", "portugués"),
+    ("es", "ro", "rumano"),
+    ("es", "ru", "ruso"),
+    ("es", "sk", "eslovaco"),
+    ("es", "sl", "esloveno"),
+    ("es", "sr", "serbio"),
+    ("es", "sv", "sueco"),
+    ("es", "th", "tailandés"),
+    ("es", "tr", "turco"),
+    ("es", "uk", "ucraniano"),
+    ("es", "vi", "vietnamita"),
+    ("es", "zh", "chino"),
+    ("fr", "ar", "arabe"),
+    ("fr", "bg", "bulgare"),
+    ("fr", "ca", "catalan"),
+    ("fr", "cs", "tchèque"),
+    ("fr", "da", "danois"),
+    ("fr", "de", "allemand"),
+    ("fr", "el", "grec"),
+    ("fr", "en", "anglais"),
+    ("fr", "es", "espagnol"),
+    ("fr", "et", "estonien"),
+    ("fr", "fi", "finnois"),
+    ("fr", "fr", "français"),
+    ("fr", "he", "hébreu"),
+    ("fr", "hi", "hindi"),
+    ("fr", "hr", "croate"),
+    ("fr", "hu", "hongrois"),
+    ("fr", "id", "indonésien"),
+    ("fr", "it", "italien"),
+    ("fr", "ja", "japonais"),
+    ("fr", "ko", "coréen"),
+    ("fr", "lt", "lituanien"),
+    ("fr", "lv", "letton"),
+    ("fr", "nb", "norvégien bokmål"),
+    ("fr", "nl", "néerlandais"),
+    ("fr", "pl", "polonais"),
+    ("fr", "pt", "portugais"),
+    ("fr", "ro", "roumain"),
+    ("fr", "ru", "russe"),
+    ("fr", "sk", "slovaque"),
+    ("fr", "sl", "slovène"),
+    ("fr", "sr", "serbe"),
+    ("fr", "sv", "suédois"),
+    ("fr", "th", "thaï"),
+    ("fr", "tr", "turc"),
+    ("fr", "uk", "ukrainien"),
+    ("fr", "vi", "vietnamien"),
+    ("fr", "zh", "chinois"),
+];
+
+/// The most likely territory of a language, from the likely subtags of CLDR, for the languages
+/// whose code is not the code of that territory.
+pub const LIKELY_TERRITORIES: &[(&str, &str)] = &[
+    ("ar", "EG"),
+    ("bn", "BD"),
+    ("ca", "ES"),
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("el", "GR"),
+    ("en", "US"),
+    ("et", "EE"),
+    ("fa", "IR"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("hy", "AM"),
+    ("ja", "JP"),
+    ("ka", "GE"),
+    ("kk", "KZ"),
+    ("ko", "KR"),
+    ("ms", "MY"),
+    ("nb", "NO"),
+    ("pa", "IN"),
+    ("sl", "SI"),
+    ("sq", "AL"),
+    ("sr", "RS"),
+    ("sv", "SE"),
+    ("sw", "TZ"),
+    ("ta", "IN"),
+    ("uk", "UA"),
+    ("ur", "PK"),
+    ("vi", "VN"),
+    ("zh", "CN"),
+];
+
+/// How percentages are written in a language, when it differs from `#%`. The `#` is replaced by
+/// the number.
+pub const PERCENT_FORMATS: &[(&str, &str)] = &[
+    ("bg", "#\u{a0}%"),
+    ("ca", "#\u{a0}%"),
+    ("cs", "#\u{a0}%"),
+    ("da", "#\u{a0}%"),
+    ("de", "#\u{a0}%"),
+    ("es", "#\u{a0}%"),
+    ("et", "#\u{a0}%"),
+    ("eu", "%\u{a0}#"),
+    ("fi", "#\u{a0}%"),
+    ("fr", "#\u{a0}%"),
+    ("lt", "#\u{a0}%"),
+    ("nb", "#\u{a0}%"),
+    ("nn", "#\u{a0}%"),
+    ("ru", "#\u{a0}%"),
+    ("sk", "#\u{a0}%"),
+    ("sv", "#\u{a0}%"),
+    ("tr", "%#"),
+    ("uk", "#\u{a0}%"),
+];
+
+/// Look up `key` in one of the tables of this module.
+pub fn lookup(table: &[(&str, &'static str)], key: &str) -> Option<&'static str> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
@@ -0,0 +1,51 @@
+use pure_rust_locales::{en_US, POSIX};
+
+#[test]
+fn country_info() {
+    let info = en_US::LC_ADDRESS::country_info();
+    assert_eq!(info.name, Some("United States"));
+    assert_eq!(info.ab2, Some("US"));
+    assert_eq!(info.ab3, Some("USA"));
+    assert_eq!(info.num, Some(840));
+    assert_eq!(info.car, Some("USA"));
+    assert_eq!(info.isbn, Some("0"));
+    assert_eq!(info.post, Some("USA"));
+
+    assert_eq!(POSIX::LC_ADDRESS::country_info().ab2, None);
+}
+
+#[test]
+fn country_codes() {
+    use pure_rust_locales::{en_CA, fr_CH};
+
+    assert_eq!(en_US::LC_ADDRESS::COUNTRY_AB2, Some("US"));
+    assert_eq!(en_US::LC_ADDRESS::COUNTRY_AB3, Some("USA"));
+    assert_eq!(en_US::LC_ADDRESS::COUNTRY_NUM, Some(840));
+    assert_eq!(en_US::LC_ADDRESS::COUNTRY_CAR, Some("USA"));
+    assert_eq!(en_US::LC_ADDRESS::LANG_AB, Some("en"));
+    assert!(en_US::LC_ADDRESS::POSTAL_FMT.is_some());
+    assert_eq!(en_US::LC_ADDRESS::COUNTRY_POST, Some("USA"));
+
+    assert_eq!(en_CA::LC_ADDRESS::COUNTRY_AB2, Some("CA"));
+    assert_eq!(fr_CH::LC_ADDRESS::COUNTRY_NUM, Some(756));
+    assert_eq!(POSIX::LC_ADDRESS::COUNTRY_AB2, None);
+}
+
+#[test]
+fn country_post() {
+    use pure_rust_locales::{af_ZA, de_DE, fr_FR};
+
+    assert_eq!(de_DE::LC_ADDRESS::COUNTRY_POST, Some("D"));
+    assert_eq!(af_ZA::LC_ADDRESS::country_info().post, Some("ZA"));
+    assert_eq!(fr_FR::LC_ADDRESS::COUNTRY_POST, None);
+    assert_eq!(POSIX::LC_ADDRESS::COUNTRY_POST, None);
+}
+
+#[test]
+#[allow(deprecated)]
+fn old_names() {
+    assert_eq!(
+        en_US::LC_ADDRESS::COUNTRY_ABTWO,
+        en_US::LC_ADDRESS::COUNTRY_AB2
+    );
+}
@@ -2,7 +2,7 @@
 fn checksum() {
     let output = std::process::Command::new("cargo")
         .env("CHECK", "1")
-        .args(&["run", "-p", "generate-api", "--", "--check"])
+        .args(["run", "-p", "generate-api", "--", "--check"])
         .output()
         .unwrap();
 
@@ -0,0 +1,12 @@
+use pure_rust_locales::{en_US, POSIX};
+
+#[test]
+fn identification_pairs() {
+    let title = en_US::LC_IDENTIFICATION::IDENTIFICATION
+        .iter()
+        .find(|(key, _)| *key == "title")
+        .map(|(_, value)| *value);
+
+    assert_eq!(title, en_US::LC_IDENTIFICATION::TITLE);
+    assert!(POSIX::LC_IDENTIFICATION::IDENTIFICATION.is_empty());
+}
@@ -0,0 +1,527 @@
+use pure_rust_locales::{Locale, LocaleData};
+
+#[test]
+fn with_language() {
+    assert_eq!(Locale::fr_CA.with_language("en"), Some(Locale::en_CA));
+    assert_eq!(Locale::en_CA.with_language("fr"), Some(Locale::fr_CA));
+    assert_eq!(Locale::de_DE_euro.with_language("de"), Some(Locale::de_DE));
+    assert_eq!(Locale::fr_CA.with_language("xx"), None);
+    assert_eq!(Locale::POSIX.with_language("en"), None);
+}
+
+#[test]
+fn script() {
+    assert_eq!(Locale::sr_RS_latin.script(), Some("Latn"));
+    assert_eq!(Locale::sr_RS.script(), Some("Cyrl"));
+    assert_eq!(Locale::en_US.script(), Some("Latn"));
+    assert_eq!(Locale::uz_UZ_cyrillic.script(), Some("Cyrl"));
+    assert_eq!(Locale::zh_TW.script(), Some("Hant"));
+    assert_eq!(Locale::POSIX.script(), None);
+}
+
+#[test]
+fn iter_languages_and_territories() {
+    let languages: Vec<_> = Locale::iter_languages().collect();
+    assert!(languages.windows(2).all(|x| x[0] < x[1]));
+    assert!(languages.contains(&"en"));
+    assert!(languages.contains(&"de"));
+    assert!(!languages.contains(&"POSIX"));
+
+    let territories: Vec<_> = Locale::iter_territories().collect();
+    assert!(territories.windows(2).all(|x| x[0] < x[1]));
+    assert!(territories.contains(&"US"));
+    assert!(territories.contains(&"CH"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalize() {
+    assert_eq!(Locale::normalize("EN-us.utf8").as_deref(), Some("en_US"));
+    assert_eq!(Locale::normalize("en_US").as_deref(), Some("en_US"));
+    assert_eq!(
+        Locale::normalize("de_de.UTF-8@EURO").as_deref(),
+        Some("de_DE@euro")
+    );
+    assert_eq!(Locale::normalize("posix").as_deref(), Some("POSIX"));
+    assert_eq!(Locale::normalize("C.UTF-8").as_deref(), Some("POSIX"));
+    assert_eq!(Locale::normalize("xx_YY"), None);
+    assert_eq!(Locale::normalize(""), None);
+}
+
+#[test]
+fn display_name_in() {
+    assert_eq!(Locale::en_US.title(), "English locale for the USA");
+    assert!(Locale::de_DE
+        .display_name_in(Locale::en_US)
+        .contains("German"));
+    assert_eq!(Locale::de_DE.display_name_in(Locale::fr_FR), "allemand");
+    assert_eq!(Locale::fr_CA.display_name_in(Locale::de_AT), "Französisch");
+    // not in the table
+    assert_eq!(
+        Locale::nds_DE.display_name_in(Locale::en_US),
+        Locale::nds_DE.title()
+    );
+    assert_eq!(
+        Locale::de_DE.display_name_in(Locale::ja_JP),
+        Locale::de_DE.title()
+    );
+}
+
+#[test]
+fn is_valid_name() {
+    for name in ["POSIX", "en_US", "de_DE@euro", "sr_RS@latin", "nds_NL"] {
+        assert!(Locale::is_valid_name(name), "{}", name);
+    }
+    for name in ["", "en", "en_us", "de_DE_euro", "en_US.UTF-8", "xx_YY", "C"] {
+        assert!(!Locale::is_valid_name(name), "{}", name);
+    }
+}
+
+#[test]
+fn compact_representation() {
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<Locale>(), 2);
+    assert_eq!(size_of::<Option<Locale>>(), size_of::<Locale>());
+}
+
+#[test]
+fn with_category() {
+    let address: Vec<_> = Locale::with_category("LC_ADDRESS").collect();
+    assert!(!address.is_empty());
+    assert!(address.contains(&Locale::en_US));
+    assert!(!address.contains(&Locale::POSIX));
+
+    let time: Vec<_> = Locale::with_category("LC_TIME").collect();
+    assert!(time.contains(&Locale::POSIX));
+
+    assert_eq!(Locale::with_category("LC_UNKNOWN").count(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn try_from_string() {
+    use std::convert::TryFrom;
+
+    let name = String::from("de_DE@euro");
+    assert_eq!(Locale::try_from(name).ok(), Some(Locale::de_DE_euro));
+    assert!(Locale::try_from(String::from("xx_YY")).is_err());
+}
+
+#[test]
+fn locale_data() {
+    let data: Vec<Box<dyn LocaleData>> = vec![Box::new(Locale::de_DE), Box::new(Locale::POSIX)];
+    assert_eq!(data[0].mon()[0], "Januar");
+    assert_eq!(data[0].decimal_point(), ",");
+    assert_eq!(data[0].int_curr_symbol(), "EUR ");
+    assert_eq!(data[1].d_fmt(), "%m/%d/%y");
+    assert_eq!(data[1].currency_symbol(), "");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_android_qualifier() {
+    assert_eq!(Locale::pt_BR.to_android_qualifier(), "pt-rBR");
+    assert_eq!(Locale::sr_RS_latin.to_android_qualifier(), "sr-rRS");
+    assert_eq!(Locale::agr_PE.to_android_qualifier(), "b+agr+PE");
+    assert_eq!(Locale::POSIX.to_android_qualifier(), "");
+}
+
+#[test]
+fn is_derived_from() {
+    assert_eq!(Locale::de_DE_euro.is_derived_from(), Some(Locale::de_DE));
+    assert_eq!(Locale::ca_AD.is_derived_from(), Some(Locale::ca_ES));
+    assert_eq!(Locale::de_DE.is_derived_from(), None);
+    assert_eq!(Locale::POSIX.is_derived_from(), None);
+}
+
+/// 64-bit FNV-1a, a hasher that gives the same result on all platforms.
+struct Fnv(u64);
+
+impl std::hash::Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+#[test]
+fn hash_is_stable() {
+    use std::hash::{Hash, Hasher};
+
+    let hash = |locale: Locale| {
+        let mut hasher = Fnv(0xcbf29ce484222325);
+        locale.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(Locale::POSIX), 0x9712e57cda78f375);
+    assert_eq!(hash(Locale::en_US), 0x4bdc2fccb122d048);
+    assert_eq!(hash(Locale::de_DE_euro), 0x9a7b6d61d3435f8e);
+
+    let hashes: std::collections::HashSet<_> = Locale::with_category("LC_IDENTIFICATION")
+        .map(hash)
+        .collect();
+    assert_eq!(
+        hashes.len(),
+        Locale::with_category("LC_IDENTIFICATION").count()
+    );
+}
+
+const _: () = assert!(Locale::POSIX.is_posix());
+const _: () = assert!(!Locale::en_US.is_posix());
+
+#[test]
+fn is_posix() {
+    assert!(Locale::default().is_posix());
+    assert_eq!(Locale::variants().filter(Locale::is_posix).count(), 1);
+}
+
+const _: &str = Locale::en_US.as_str();
+
+#[test]
+fn as_str() {
+    const NAME: &str = Locale::de_DE_euro.as_str();
+    assert_eq!(NAME, "de_DE@euro");
+    for locale in Locale::variants() {
+        assert_eq!(locale.as_str(), locale.to_string());
+    }
+}
+
+#[test]
+fn name_bytes() {
+    const NAME: &[u8] = Locale::de_DE_euro.name_bytes();
+    assert_eq!(NAME, b"de_DE@euro");
+    assert_eq!(Locale::POSIX.name_bytes(), b"POSIX");
+    for locale in Locale::with_category("LC_IDENTIFICATION") {
+        assert_eq!(locale.name_bytes(), locale.to_string().as_bytes());
+    }
+}
+
+#[test]
+fn for_currency_and_language() {
+    assert_eq!(
+        Locale::for_currency_and_language("EUR", "de"),
+        Some(Locale::de_DE)
+    );
+    assert_eq!(
+        Locale::for_currency_and_language("CHF", "de"),
+        Some(Locale::de_CH)
+    );
+    assert_eq!(Locale::for_currency_and_language("EUR", "xx"), None);
+    assert_eq!(Locale::for_currency_and_language("JPY", "de"), None);
+}
+
+#[test]
+fn from_components() {
+    assert_eq!(
+        Locale::from_components("de", Some("DE"), Some("euro")),
+        Some(Locale::de_DE_euro)
+    );
+    assert_eq!(
+        Locale::from_components("de", Some("DE"), None),
+        Some(Locale::de_DE)
+    );
+    assert_eq!(Locale::from_components("eo", None, None), Some(Locale::eo));
+    assert_eq!(Locale::from_components("de", Some("JP"), None), None);
+    assert_eq!(Locale::from_components("de", None, None), None);
+}
+
+#[test]
+fn nested_locales_module() {
+    use pure_rust_locales::locales;
+
+    assert_eq!(locales::de_DE::LC_TIME::D_FMT, "%d.%m.%Y");
+    assert_eq!(
+        locales::en_US::LC_NUMERIC::DECIMAL_POINT,
+        pure_rust_locales::en_US::LC_NUMERIC::DECIMAL_POINT
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn format_percent() {
+    assert_eq!(Locale::en_US.format_percent(50.0), "50%");
+    assert_eq!(Locale::fr_FR.format_percent(50.0), "50\u{a0}%");
+    assert_eq!(Locale::fr_FR.format_percent(12.5), "12,5\u{a0}%");
+    assert_eq!(Locale::de_DE.format_percent(-1234.5), "-1.234,5\u{a0}%");
+    assert_eq!(Locale::tr_TR.format_percent(50.0), "%50");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn try_from_os_str() {
+    use std::ffi::OsString;
+
+    let name = OsString::from("de_DE.UTF-8@euro");
+    assert_eq!(
+        Locale::try_from_os_str(&name).ok(),
+        Some(Locale::de_DE_euro)
+    );
+    assert_eq!(
+        Locale::try_from_os_str(&OsString::from("C.UTF-8")).ok(),
+        Some(Locale::POSIX)
+    );
+    assert!(Locale::try_from_os_str(&OsString::from("xx_YY")).is_err());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        let name = OsString::from_vec(b"de_DE.\xff".to_vec());
+        assert!(Locale::try_from_os_str(&name).is_err());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn report_missing_core_keys() {
+    assert_eq!(Locale::report_missing_core_keys(), []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn from_language_list() {
+    let locales: Vec<_> = Locale::from_language_list("de:fr:en").collect();
+    assert_eq!(locales, [Locale::de_DE, Locale::fr_FR, Locale::en_US]);
+
+    let locales: Vec<_> = Locale::from_language_list("pt_BR:xx:de_AT.UTF-8::C").collect();
+    assert_eq!(locales, [Locale::pt_BR, Locale::de_AT, Locale::POSIX]);
+}
+
+#[test]
+fn common_ancestor() {
+    assert_eq!(
+        Locale::common_ancestor(&[Locale::en_US, Locale::en_GB]),
+        Some(Locale::POSIX)
+    );
+    assert_eq!(
+        Locale::common_ancestor(&[Locale::en_US, Locale::de_DE]),
+        Some(Locale::POSIX)
+    );
+    assert_eq!(
+        Locale::common_ancestor(&[Locale::de_DE_euro, Locale::de_DE]),
+        Some(Locale::de_DE)
+    );
+    assert_eq!(
+        Locale::common_ancestor(&[Locale::sr_RS, Locale::sr_RS_latin]),
+        Some(Locale::sr_RS)
+    );
+    assert_eq!(
+        Locale::common_ancestor(&[Locale::fr_CA]),
+        Some(Locale::fr_CA)
+    );
+    assert_eq!(Locale::common_ancestor(&[]), None);
+}
+
+#[test]
+fn variants_matching() {
+    let english: Vec<_> = Locale::variants_matching("en_*").collect();
+    assert!(english.contains(&Locale::en_US));
+    assert!(english.contains(&Locale::en_IE_euro));
+    assert!(english.iter().all(|x| x.to_string().starts_with("en_")));
+
+    let swiss: Vec<_> = Locale::variants_matching("*_CH").collect();
+    assert!(swiss.contains(&Locale::de_CH));
+    assert!(swiss.contains(&Locale::fr_CH));
+    assert!(swiss.iter().all(|x| x.to_string().ends_with("_CH")));
+
+    let names: Vec<_> = Locale::variants_matching("?e_D?").collect();
+    assert_eq!(names, [Locale::de_DE]);
+    assert_eq!(Locale::variants_matching("POSIX").count(), 1);
+    assert_eq!(Locale::variants_matching("").count(), 0);
+    assert!(Locale::variants_matching("*").any(|x| x == Locale::POSIX));
+}
+
+#[test]
+fn filter_by_name() {
+    let swiss: Vec<_> = Locale::filter_by_name(|x| x.contains("_CH")).collect();
+    assert!(swiss.contains(&Locale::de_CH));
+    assert!(swiss.contains(&Locale::it_CH));
+    assert!(swiss.iter().all(|x| x.to_string().contains("_CH")));
+    assert_eq!(
+        swiss,
+        Locale::variants_matching("*_CH*").collect::<Vec<_>>()
+    );
+
+    assert_eq!(Locale::filter_by_name(|_| false).count(), 0);
+}
+
+#[test]
+fn from_str_round_trip() {
+    for locale in Locale::variants() {
+        assert_eq!(locale.to_string().parse::<Locale>().ok(), Some(locale));
+    }
+    assert!("xx_XX".parse::<Locale>().is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_posix_source() {
+    let source = Locale::en_US.to_posix_source("LC_MONETARY");
+    assert!(source.starts_with("LC_MONETARY\n"));
+    assert!(source.ends_with("END LC_MONETARY\n"));
+    assert!(source.contains("\ncurrency_symbol \"$\"\n"));
+    assert!(source.contains("\nint_curr_symbol \"USD \"\n"));
+    assert!(source.contains("\nmon_grouping 3;3\n"));
+    assert!(source.contains("\nfrac_digits 2\n"));
+
+    let source = Locale::de_DE.to_posix_source("LC_TIME");
+    assert!(source.contains("\nabday \"So\";\"Mo\";\"Di\";\"Mi\";\"Do\";\"Fr\";\"Sa\"\n"));
+    assert!(source.contains("\"M<U00E4>rz\""));
+    assert!(source.contains("\nd_fmt \"%d.%m.%Y\"\n"));
+
+    let source = Locale::fr_FR.to_posix_source("LC_NUMERIC");
+    assert!(source.contains("\nthousands_sep \"<U202F>\"\n"));
+
+    assert_eq!(Locale::en_US.to_posix_source("LC_UNKNOWN"), "");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn closest_by_distance() {
+    assert_eq!(
+        Locale::closest_by_distance("en_USS"),
+        Some((Locale::en_US, 1))
+    );
+    assert_eq!(
+        Locale::closest_by_distance("nl_NM"),
+        Some((Locale::nl_NL, 1))
+    );
+    assert_eq!(
+        Locale::closest_by_distance("de_DE"),
+        Some((Locale::de_DE, 0))
+    );
+    assert_eq!(
+        Locale::closest_by_distance("de_DE@eur"),
+        Some((Locale::de_DE_euro, 1))
+    );
+    assert_eq!(Locale::closest_by_distance("en_UK").map(|x| x.1), Some(1));
+}
+
+#[test]
+fn variants() {
+    assert!(Locale::ALL.contains(&Locale::POSIX));
+    assert!(Locale::ALL.contains(&Locale::en_US));
+    assert!(Locale::variants().eq(Locale::ALL.iter().copied()));
+
+    let names: Vec<_> = Locale::variants().map(|x| x.to_string()).collect();
+    assert!(names.windows(2).all(|x| x[0] < x[1]));
+    assert_eq!(Locale::ALL.len(), Locale::variants_matching("*").count());
+    assert_eq!(
+        Locale::ALL.len(),
+        Locale::with_category("LC_IDENTIFICATION").count() + 1
+    );
+}
+
+#[test]
+fn as_language_territory() {
+    assert_eq!(Locale::pt_BR.as_language_territory(), ("pt", Some("BR")));
+    assert_eq!(
+        Locale::de_DE_euro.as_language_territory(),
+        ("de", Some("DE"))
+    );
+    assert_eq!(Locale::eo.as_language_territory(), ("eo", None));
+    assert_eq!(Locale::POSIX.as_language_territory(), ("POSIX", None));
+}
+
+#[test]
+fn language_territory() {
+    assert_eq!(Locale::en_US.language(), "en");
+    assert_eq!(Locale::en_US.territory(), Some("US"));
+    assert_eq!(Locale::de_DE_euro.language(), "de");
+    assert_eq!(Locale::de_DE_euro.territory(), Some("DE"));
+    assert_eq!(Locale::ca_ES_valencia.language(), "ca");
+    assert_eq!(Locale::ca_ES_valencia.territory(), Some("ES"));
+    assert_eq!(Locale::eo.territory(), None);
+    assert_eq!(Locale::POSIX.language(), "POSIX");
+    assert_eq!(Locale::POSIX.territory(), None);
+
+    const LANGUAGE: &str = Locale::ja_JP.language();
+    assert_eq!(LANGUAGE, "ja");
+}
+
+#[test]
+fn ord() {
+    let mut locales = [Locale::fr_FR, Locale::en_US, Locale::de_DE];
+    locales.sort();
+    assert_eq!(locales, [Locale::de_DE, Locale::en_US, Locale::fr_FR]);
+
+    assert!(Locale::ALL
+        .windows(2)
+        .all(|x| x[0] < x[1] && x[0].as_str() < x[1].as_str()));
+}
+
+#[test]
+fn find_fallback() {
+    assert_eq!(Locale::find_fallback("en_US").ok(), Some(Locale::en_US));
+    assert_eq!(
+        Locale::find_fallback("en_US.UTF-8").ok(),
+        Some(Locale::en_US)
+    );
+    assert_eq!(
+        Locale::find_fallback("de_DE.UTF-8@euro").ok(),
+        Some(Locale::de_DE_euro)
+    );
+    assert_eq!(
+        Locale::find_fallback("en_US@euro").ok(),
+        Some(Locale::en_US)
+    );
+    assert_eq!(Locale::find_fallback("eo_XX.UTF-8").ok(), Some(Locale::eo));
+    // there is no `pt` locale to fall back to
+    assert_eq!(Locale::find_fallback("pt_XX").ok(), None);
+    assert_eq!(Locale::find_fallback("EN_us").ok(), None);
+}
+
+#[test]
+fn eq_ignoring_codeset() {
+    assert!(Locale::de_DE_euro.eq_ignoring_codeset("de_DE.UTF-8@euro"));
+    assert!(Locale::de_DE_euro.eq_ignoring_codeset("de_DE@euro"));
+    assert!(!Locale::de_DE_euro.eq_ignoring_codeset("de_DE"));
+    assert!(!Locale::de_DE_euro.eq_ignoring_codeset("de_DE.UTF-8"));
+    assert!(Locale::de_DE.eq_ignoring_codeset("de_DE.ISO-8859-1"));
+    assert!(!Locale::de_DE.eq_ignoring_codeset("de_DE@euro"));
+    assert!(!Locale::de_DE.eq_ignoring_codeset("de_de.UTF-8"));
+    assert!(!Locale::de_DE.eq_ignoring_codeset("de"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn into_string() {
+    assert_eq!(String::from(Locale::de_DE_euro), "de_DE@euro");
+    assert_eq!(String::from(&Locale::POSIX), "POSIX");
+    let name: String = Locale::en_US.into();
+    assert_eq!(name, Locale::en_US.to_string());
+}
+
+#[cfg(feature = "all-locales")]
+#[test]
+fn all_names_joined() {
+    let names: Vec<_> = Locale::ALL_NAMES_JOINED.split(',').collect();
+    assert_eq!(names.len(), Locale::COUNT);
+    assert_eq!(Locale::COUNT, Locale::ALL.len());
+    assert!(names
+        .iter()
+        .copied()
+        .eq(Locale::variants().map(|x| x.as_str())));
+    assert!(names.contains(&"de_DE@euro"));
+}
+
+#[test]
+fn get_in() {
+    let mut greetings = [""; Locale::COUNT];
+    greetings[Locale::de_DE.variant_index()] = "Guten Tag";
+    greetings[Locale::en_US.variant_index()] = "Hello";
+
+    assert_eq!(Locale::de_DE.get_in(&greetings), Some(&"Guten Tag"));
+    assert_eq!(Locale::en_US.get_in(&greetings), Some(&"Hello"));
+    assert_eq!(Locale::fr_FR.get_in(&greetings), Some(&""));
+    assert_eq!(Locale::en_US.get_in::<&str>(&[]), None);
+    assert!(Locale::variants()
+        .enumerate()
+        .all(|(i, x)| x.variant_index() == i));
+}
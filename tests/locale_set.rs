@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use pure_rust_locales::{Locale, LocaleSet};
+use std::ffi::OsString;
+
+fn lookup(vars: &[(&str, &str)]) -> LocaleSet {
+    LocaleSet::from_lookup(|name| {
+        vars.iter()
+            .find(|(x, _)| *x == name)
+            .map(|(_, value)| OsString::from(value))
+    })
+}
+
+#[test]
+fn category_variables() {
+    let set = lookup(&[
+        ("LANG", "en_US.UTF-8"),
+        ("LC_TIME", "de_DE.UTF-8"),
+        ("LC_MONETARY", "fr_FR@euro"),
+    ]);
+    assert_eq!(set.category("LC_TIME"), Locale::de_DE);
+    assert_eq!(set.category("LC_MONETARY"), Locale::fr_FR_euro);
+    assert_eq!(set.category("LC_NUMERIC"), Locale::en_US);
+    assert_eq!(set.category("LC_UNKNOWN"), Locale::en_US);
+}
+
+#[test]
+fn lc_all_overrides() {
+    let set = lookup(&[
+        ("LANG", "en_US.UTF-8"),
+        ("LC_ALL", "nl_NL.UTF-8"),
+        ("LC_TIME", "de_DE.UTF-8"),
+    ]);
+    assert_eq!(set.category("LC_TIME"), Locale::nl_NL);
+    assert_eq!(set.category("LC_NUMERIC"), Locale::nl_NL);
+
+    // An empty `LC_ALL` is ignored.
+    let set = lookup(&[("LC_ALL", ""), ("LC_TIME", "de_DE.UTF-8")]);
+    assert_eq!(set.category("LC_TIME"), Locale::de_DE);
+    assert_eq!(set.category("LC_NUMERIC"), Locale::POSIX);
+}
+
+#[test]
+fn unknown_locales() {
+    let set = lookup(&[("LANG", "xx_XX"), ("LC_TIME", "C.UTF-8")]);
+    assert_eq!(set.category("LC_TIME"), Locale::POSIX);
+    assert_eq!(set.category("LC_NUMERIC"), Locale::POSIX);
+}
+
+#[test]
+fn from_env() {
+    std::env::remove_var("LC_ALL");
+    std::env::set_var("LANG", "en_GB.UTF-8");
+    std::env::set_var("LC_TIME", "de_DE.UTF-8");
+    std::env::set_var("LC_MONETARY", "ja_JP.UTF-8");
+    std::env::remove_var("LC_NUMERIC");
+
+    let set = LocaleSet::from_env();
+    assert_eq!(set.category("LC_TIME"), Locale::de_DE);
+    assert_eq!(set.category("LC_MONETARY"), Locale::ja_JP);
+    assert_eq!(set.category("LC_NUMERIC"), Locale::en_GB);
+}
@@ -11,3 +11,14 @@ fn locale_match() {
     assert_eq!(locale_match!(locale => LC_NUMERIC::GROUPING), &[3, 3]);
     assert_eq!(locale_match!(locale => LC_NUMERIC::THOUSANDS_SEP), ".");
 }
+
+#[test]
+fn lc_time_struct() {
+    use pure_rust_locales::{lc_time_struct, TimeFields};
+
+    const DE_DE: TimeFields = lc_time_struct!(Locale::de_DE);
+
+    assert_eq!(DE_DE.d_fmt, pure_rust_locales::de_DE::LC_TIME::D_FMT);
+    assert_eq!(DE_DE.mon[0], "Januar");
+    assert_eq!(DE_DE.first_weekday, Some(2));
+}
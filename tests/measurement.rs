@@ -0,0 +1,17 @@
+use pure_rust_locales::{de_DE, en_CA, en_US, fr_FR, POSIX};
+
+#[test]
+fn measurement() {
+    assert_eq!(en_US::LC_MEASUREMENT::MEASUREMENT, 2);
+    assert_eq!(fr_FR::LC_MEASUREMENT::MEASUREMENT, 1);
+    assert_eq!(POSIX::LC_MEASUREMENT::MEASUREMENT, 1);
+    // copied from `i18n`
+    assert_eq!(en_CA::LC_MEASUREMENT::MEASUREMENT, 1);
+}
+
+#[test]
+fn is_metric() {
+    assert!(de_DE::LC_MEASUREMENT::is_metric());
+    assert!(!en_US::LC_MEASUREMENT::is_metric());
+    assert!(POSIX::LC_MEASUREMENT::is_metric());
+}
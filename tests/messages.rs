@@ -0,0 +1,51 @@
+use pure_rust_locales::{de_DE, en_US, ja_JP, uk_UA, POSIX};
+
+#[test]
+fn accepts_first_char() {
+    assert!(en_US::LC_MESSAGES::accepts_yes("y"));
+    assert!(en_US::LC_MESSAGES::accepts_yes("Yes"));
+    assert!(en_US::LC_MESSAGES::accepts_yes("1"));
+    assert!(!en_US::LC_MESSAGES::accepts_yes("no"));
+    assert!(!en_US::LC_MESSAGES::accepts_yes(""));
+    assert!(en_US::LC_MESSAGES::accepts_no("no"));
+
+    assert!(de_DE::LC_MESSAGES::accepts_yes("ja"));
+    assert!(!de_DE::LC_MESSAGES::accepts_no("ja"));
+    assert!(de_DE::LC_MESSAGES::accepts_no("nein"));
+
+    assert!(POSIX::LC_MESSAGES::accepts_yes("y"));
+    assert!(!POSIX::LC_MESSAGES::accepts_yes("1"));
+}
+
+#[test]
+fn accepts_words() {
+    assert!(ja_JP::LC_MESSAGES::accepts_yes("はい"));
+    assert!(ja_JP::LC_MESSAGES::accepts_yes("ｙ"));
+    assert!(ja_JP::LC_MESSAGES::accepts_no("いいえ"));
+    assert!(!ja_JP::LC_MESSAGES::accepts_yes("いいえ"));
+    assert_eq!(ja_JP::LC_MESSAGES::YES_PATTERN.words, ["はい", "ハイ"]);
+
+    // `^([+1Yy]|[Тт][Аа][Кк]?)$` must match the whole response
+    assert!(uk_UA::LC_MESSAGES::accepts_yes("так"));
+    assert!(uk_UA::LC_MESSAGES::accepts_yes("Та"));
+    assert!(uk_UA::LC_MESSAGES::accepts_yes("y"));
+    assert!(!uk_UA::LC_MESSAGES::accepts_yes("yes"));
+    assert!(!uk_UA::LC_MESSAGES::accepts_yes("такі"));
+    assert!(uk_UA::LC_MESSAGES::accepts_no("ні"));
+}
+
+#[test]
+fn expressions() {
+    use pure_rust_locales::de_AT;
+
+    // the POSIX regular expressions are kept as they are
+    assert!(en_US::LC_MESSAGES::YESEXPR.starts_with("^[+1yY]"));
+    assert!(en_US::LC_MESSAGES::NOEXPR.starts_with("^[-0nN]"));
+    assert_eq!(POSIX::LC_MESSAGES::YESEXPR, "^[yY]");
+    assert_eq!(POSIX::LC_MESSAGES::YESSTR, Some("Yes"));
+
+    // `de_AT` only copies `LC_MESSAGES` of `de_DE`
+    assert_eq!(de_AT::LC_MESSAGES::YESEXPR, de_DE::LC_MESSAGES::YESEXPR);
+    assert_eq!(de_AT::LC_MESSAGES::NOSTR, de_DE::LC_MESSAGES::NOSTR);
+    assert!(de_AT::LC_MESSAGES::YESEXPR.contains("jJ"));
+}
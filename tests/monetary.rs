@@ -0,0 +1,127 @@
+#[cfg(feature = "alloc")]
+use pure_rust_locales::{de_DE, en_HK};
+use pure_rust_locales::{en_US, iso4217_minor_units, ja_JP, POSIX};
+
+#[test]
+fn effective_frac_digits() {
+    assert_eq!(ja_JP::LC_MONETARY::effective_frac_digits(), 0);
+    assert_eq!(en_US::LC_MONETARY::effective_frac_digits(), 2);
+    // `FRAC_DIGITS` is `-1` and there is no currency
+    assert_eq!(POSIX::LC_MONETARY::FRAC_DIGITS, -1);
+    assert_eq!(POSIX::LC_MONETARY::effective_frac_digits(), 2);
+}
+
+#[test]
+fn minor_units() {
+    assert_eq!(iso4217_minor_units("JPY"), Some(0));
+    assert_eq!(iso4217_minor_units("USD"), Some(2));
+    assert_eq!(iso4217_minor_units("KWD"), Some(3));
+    assert_eq!(iso4217_minor_units("usd"), None);
+    assert_eq!(iso4217_minor_units(""), None);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn sample_amount_string() {
+    assert_eq!(en_US::LC_MONETARY::sample_amount_string(false), "$1,234.56");
+    assert_eq!(en_US::LC_MONETARY::sample_amount_string(true), "-$1,234.56");
+    assert_eq!(
+        de_DE::LC_MONETARY::sample_amount_string(false),
+        "1.234,56 €"
+    );
+    assert_eq!(
+        de_DE::LC_MONETARY::sample_amount_string(true),
+        "-1.234,56 €"
+    );
+    // The sign of negative amounts is written as parentheses
+    assert_eq!(
+        en_HK::LC_MONETARY::sample_amount_string(true),
+        "(HK$1,234.56)"
+    );
+    // Rounded to the minor units of the yen
+    assert_eq!(ja_JP::LC_MONETARY::sample_amount_string(false), "￥1,235");
+}
+
+#[test]
+fn effective_grouping() {
+    use pure_rust_locales::{hi_IN, kab_DZ, GroupSize::*};
+
+    assert_eq!(
+        en_US::LC_MONETARY::effective_grouping(),
+        &[Repeat(3), Repeat(3)]
+    );
+    assert_eq!(
+        hi_IN::LC_MONETARY::effective_grouping(),
+        &[Repeat(3), Repeat(2)]
+    );
+    assert_eq!(hi_IN::LC_NUMERIC::typed_grouping(), &[Repeat(3)]);
+    // no `MON_THOUSANDS_SEP`
+    assert_eq!(kab_DZ::LC_MONETARY::MON_GROUPING, &[3]);
+    assert_eq!(kab_DZ::LC_MONETARY::effective_grouping(), &[NoMore]);
+    assert_eq!(POSIX::LC_MONETARY::effective_grouping(), &[NoMore]);
+}
+
+#[test]
+fn typed_entries() {
+    use pure_rust_locales::{DataValue, Locale};
+
+    let entries: Vec<_> = Locale::de_DE.typed_entries("LC_MONETARY").collect();
+    assert!(entries.contains(&("CURRENCY_SYMBOL", DataValue::Str("€"))));
+    assert!(entries.contains(&("MON_GROUPING", DataValue::IntSlice(&[3, 3]))));
+    assert!(entries.contains(&("FRAC_DIGITS", DataValue::Int(2))));
+    // items without a value are skipped
+    assert!(!entries.iter().any(|(name, _)| *name == "INT_N_CS_PRECEDES"));
+
+    assert_eq!(Locale::de_DE.typed_entries("LC_FOO").count(), 0);
+}
+
+#[test]
+fn sign_position() {
+    use pure_rust_locales::{de_DE, CsPrecedes, SignPosition};
+
+    assert_eq!(en_US::LC_MONETARY::P_SIGN_POSN, 1);
+    assert_eq!(
+        en_US::LC_MONETARY::p_sign_posn(),
+        Some(SignPosition::BeforeAll)
+    );
+    assert_eq!(
+        en_US::LC_MONETARY::p_cs_precedes(),
+        Some(CsPrecedes::Precedes)
+    );
+    assert_eq!(
+        de_DE::LC_MONETARY::p_cs_precedes(),
+        Some(CsPrecedes::Follows)
+    );
+    // not specified
+    assert_eq!(POSIX::LC_MONETARY::p_sign_posn(), None);
+    assert_eq!(POSIX::LC_MONETARY::n_cs_precedes(), None);
+    assert_eq!(SignPosition::from_i64(4), Some(SignPosition::AfterSymbol));
+    assert_eq!(SignPosition::Parentheses as i64, 0);
+}
+
+#[test]
+fn negative_display() {
+    use pure_rust_locales::{de_CH, en_HK, he_IL, NegativeDisplay};
+
+    assert_eq!(
+        en_US::LC_MONETARY::negative_display(),
+        NegativeDisplay::LeadingMinus
+    );
+    assert_eq!(
+        de_CH::LC_MONETARY::negative_display(),
+        NegativeDisplay::LeadingSymbolSign
+    );
+    assert_eq!(
+        he_IL::LC_MONETARY::negative_display(),
+        NegativeDisplay::TrailingMinus
+    );
+    assert_eq!(
+        en_HK::LC_MONETARY::negative_display(),
+        NegativeDisplay::Parentheses
+    );
+    // no `NEGATIVE_SIGN` and no position
+    assert_eq!(
+        POSIX::LC_MONETARY::negative_display(),
+        NegativeDisplay::Custom
+    );
+}
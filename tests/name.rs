@@ -0,0 +1,49 @@
+use pure_rust_locales::NameToken::*;
+use pure_rust_locales::{en_US, ja_JP, POSIX};
+
+#[test]
+fn parse_name_fmt() {
+    let tokens: Vec<_> = en_US::LC_NAME::parse_name_fmt().collect();
+    assert_eq!(
+        tokens,
+        [Salutation, Separator, GivenName, Separator, OtherNames, Separator, FamilyName]
+    );
+
+    let tokens: Vec<_> = ja_JP::LC_NAME::parse_name_fmt().collect();
+    assert_eq!(
+        tokens,
+        [Profession, Separator, FamilyName, Separator, GivenName]
+    );
+
+    let tokens: Vec<_> = POSIX::LC_NAME::parse_name_fmt().collect();
+    assert_eq!(
+        tokens,
+        [Profession, Separator, GivenName, Separator, OtherNames, Separator, FamilyName]
+    );
+}
+
+#[test]
+fn name_fmt() {
+    use pure_rust_locales::{de_AT, de_DE};
+
+    assert_eq!(ja_JP::LC_NAME::NAME_FMT, "%p%t%f%t%g");
+    assert_eq!(en_US::LC_NAME::NAME_FMT, "%d%t%g%t%m%t%f");
+    assert_eq!(POSIX::LC_NAME::NAME_FMT, "%p%t%g%t%m%t%f");
+    assert_eq!(de_DE::LC_NAME::NAME_MR, "Herr");
+    assert_eq!(de_DE::LC_NAME::NAME_GEN, "");
+
+    // `de_AT` only copies `de_DE`
+    assert_eq!(de_AT::LC_NAME::NAME_FMT, de_DE::LC_NAME::NAME_FMT);
+    assert_eq!(de_AT::LC_NAME::NAME_MRS, "Frau");
+}
+
+#[test]
+fn salutation() {
+    use pure_rust_locales::{de_DE, Salutation};
+
+    assert_eq!(de_DE::LC_NAME::salutation(Salutation::Mr), "Herr");
+    assert_eq!(de_DE::LC_NAME::salutation(Salutation::Mrs), "Frau");
+    assert_eq!(de_DE::LC_NAME::salutation(Salutation::General), "");
+    assert_eq!(ja_JP::LC_NAME::salutation(Salutation::General), "様");
+    assert_eq!(POSIX::LC_NAME::salutation(Salutation::Mr), "");
+}
@@ -0,0 +1,200 @@
+use pure_rust_locales::{de_DE, en_IN, en_US, fr_FR, GroupSize, GroupingStyle, POSIX};
+
+#[test]
+fn thousands_sep_str() {
+    assert_eq!(en_US::LC_NUMERIC::thousands_sep_str(), ",");
+    assert_eq!(fr_FR::LC_NUMERIC::thousands_sep_str(), "\u{202f}");
+    assert_ne!(fr_FR::LC_NUMERIC::thousands_sep_str(), " ");
+}
+
+#[test]
+fn grouping() {
+    use pure_rust_locales::{aa_DJ, en_IN, hi_IN, GroupSizes, POSIX};
+    use GroupSize::*;
+
+    assert!(en_US::LC_NUMERIC::has_grouping());
+    assert!(!aa_DJ::LC_NUMERIC::has_grouping());
+    assert!(!POSIX::LC_NUMERIC::has_grouping());
+
+    let sizes = |x: GroupSizes| x.take(4).collect::<Vec<_>>();
+    assert_eq!(sizes(en_US::LC_NUMERIC::group_sizes()), [3, 3, 3, 3]);
+    assert_eq!(sizes(en_IN::LC_NUMERIC::group_sizes()), [3, 2, 2, 2]);
+    assert_eq!(sizes(hi_IN::LC_MONETARY::mon_group_sizes()), [3, 2, 2, 2]);
+    assert_eq!(sizes(aa_DJ::LC_NUMERIC::group_sizes()), []);
+    assert_eq!(sizes(POSIX::LC_NUMERIC::group_sizes()), []);
+    assert_eq!(sizes(GroupSizes::new(&[])), []);
+    assert_eq!(sizes(GroupSizes::new(&[NoMore])), []);
+    assert_eq!(sizes(GroupSizes::new(&[Repeat(3)])), [3, 3, 3, 3]);
+    assert_eq!(sizes(GroupSizes::new(&[Repeat(3), NoMore])), [3]);
+    assert_eq!(
+        sizes(GroupSizes::new(&[Repeat(3), Repeat(2)])),
+        [3, 2, 2, 2]
+    );
+}
+
+#[test]
+fn grouping_struct() {
+    use pure_rust_locales::{hi_IN, Grouping};
+    use GroupSize::*;
+
+    let grouping = en_US::LC_NUMERIC::grouping();
+    assert_eq!(grouping.as_slice(), en_US::LC_NUMERIC::typed_grouping());
+    assert!(grouping.repeats_last());
+    assert_eq!(
+        grouping.group_sizes().take(3).collect::<Vec<_>>(),
+        [3, 3, 3]
+    );
+
+    let grouping = hi_IN::LC_MONETARY::mon_grouping();
+    assert_eq!(grouping.as_slice(), &[Repeat(3), Repeat(2)]);
+    assert!(grouping.repeats_last());
+    assert_eq!(
+        grouping.group_sizes().take(3).collect::<Vec<_>>(),
+        [3, 2, 2]
+    );
+    assert_eq!(
+        en_IN::LC_NUMERIC::grouping(),
+        Grouping::new(&[Repeat(3), Repeat(2)])
+    );
+
+    assert!(!POSIX::LC_NUMERIC::grouping().is_grouped());
+    assert!(!POSIX::LC_NUMERIC::grouping().repeats_last());
+    assert!(!Grouping::new(&[Repeat(3), NoMore]).repeats_last());
+    assert!(Grouping::new(&[Repeat(3)]).repeats_last());
+    assert!(!Grouping::new(&[]).is_grouped());
+}
+
+#[test]
+fn typed_grouping() {
+    use GroupSize::*;
+
+    assert_eq!(en_US::LC_NUMERIC::typed_grouping(), &[Repeat(3), Repeat(3)]);
+    assert_eq!(en_IN::LC_NUMERIC::typed_grouping(), &[Repeat(3), Repeat(2)]);
+    assert_eq!(POSIX::LC_NUMERIC::GROUPING, &[-1]);
+    assert_eq!(POSIX::LC_NUMERIC::typed_grouping(), &[NoMore]);
+    assert_eq!(POSIX::LC_MONETARY::typed_mon_grouping(), &[NoMore]);
+}
+
+#[test]
+fn grouping_style() {
+    use pure_rust_locales::{aa_DJ, hi_IN};
+
+    assert_eq!(en_US::LC_NUMERIC::grouping_style(), GroupingStyle::Western);
+    assert_eq!(en_IN::LC_NUMERIC::grouping_style(), GroupingStyle::Indian);
+    // `hi_IN` only uses the Indian grouping for money
+    assert_eq!(hi_IN::LC_NUMERIC::grouping_style(), GroupingStyle::Western);
+    assert_eq!(hi_IN::LC_MONETARY::MON_GROUPING, &[3, 2]);
+    assert_eq!(POSIX::LC_NUMERIC::grouping_style(), GroupingStyle::None);
+    assert_eq!(aa_DJ::LC_NUMERIC::grouping_style(), GroupingStyle::None);
+}
+
+#[test]
+fn decimal_is_comma() {
+    assert!(de_DE::LC_NUMERIC::decimal_is_comma());
+    assert!(fr_FR::LC_NUMERIC::decimal_is_comma());
+    assert!(!en_US::LC_NUMERIC::decimal_is_comma());
+    assert!(!POSIX::LC_NUMERIC::decimal_is_comma());
+}
+
+#[test]
+fn input_separators() {
+    assert_eq!(de_DE::LC_NUMERIC::input_separators(), (',', Some('.')));
+    assert_eq!(fr_FR::LC_NUMERIC::input_separators(), (',', Some('.')));
+    assert_eq!(en_US::LC_NUMERIC::input_separators(), ('.', None));
+    assert_eq!(POSIX::LC_NUMERIC::input_separators(), ('.', None));
+}
+
+/// A fixed-size buffer on the stack.
+struct Buffer {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Buffer {
+            bytes: [0; 32],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl core::fmt::Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn write_grouped() {
+    let grouped = |write: fn(&mut Buffer, &str) -> core::fmt::Result, digits| {
+        let mut buffer = Buffer::new();
+        write(&mut buffer, digits).unwrap();
+        buffer.as_str().to_owned()
+    };
+    assert_eq!(
+        grouped(en_US::LC_NUMERIC::write_grouped, "1234567"),
+        "1,234,567"
+    );
+    assert_eq!(
+        grouped(en_IN::LC_NUMERIC::write_grouped, "1234567"),
+        "12,34,567"
+    );
+    assert_eq!(
+        grouped(fr_FR::LC_NUMERIC::write_grouped, "1234567"),
+        "1\u{202f}234\u{202f}567"
+    );
+    assert_eq!(grouped(en_US::LC_NUMERIC::write_grouped, "123"), "123");
+    assert_eq!(grouped(en_US::LC_NUMERIC::write_grouped, ""), "");
+    assert_eq!(
+        grouped(POSIX::LC_NUMERIC::write_grouped, "1234567"),
+        "1234567"
+    );
+
+    let mut buffer = Buffer::new();
+    let digits = "1".repeat(40);
+    assert!(en_US::LC_NUMERIC::write_grouped(&mut buffer, &digits).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn write_grouped_matches_string() {
+    for digits in ["1", "12345", "1234567890"] {
+        let mut buffer = Buffer::new();
+        de_DE::LC_NUMERIC::write_grouped(&mut buffer, digits).unwrap();
+
+        let mut string = String::new();
+        de_DE::LC_NUMERIC::write_grouped(&mut string, digits).unwrap();
+        assert_eq!(buffer.as_str(), string);
+
+        let percent = pure_rust_locales::Locale::de_DE.format_percent(digits.parse().unwrap());
+        assert!(percent.starts_with(&string));
+    }
+}
+
+#[test]
+fn shared_grouping() {
+    use pure_rust_locales::{bg_BG, cs_CZ, kab_DZ};
+
+    // both refer to the same constant in `lib.rs`, which rustc places once, although the language
+    // doesn't guarantee it, see the `slices` module
+    assert_eq!(bg_BG::LC_NUMERIC::GROUPING, cs_CZ::LC_NUMERIC::GROUPING);
+    assert!(core::ptr::eq(
+        bg_BG::LC_NUMERIC::GROUPING,
+        cs_CZ::LC_NUMERIC::GROUPING
+    ));
+    assert!(core::ptr::eq(
+        fr_FR::LC_NUMERIC::GROUPING,
+        kab_DZ::LC_NUMERIC::GROUPING
+    ));
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "alloc")]
+
+use pure_rust_locales::{en_US, Overlay};
+use std::borrow::Cow;
+
+#[test]
+fn get_str_cow() {
+    let mut overlay = Overlay::new();
+    overlay.set("LC_TIME", "D_FMT", "%Y-%m-%d");
+
+    let d_fmt = overlay.get_str_cow("LC_TIME", "D_FMT", en_US::LC_TIME::D_FMT);
+    assert_eq!(d_fmt, Cow::<str>::Owned("%Y-%m-%d".to_string()));
+    assert!(matches!(d_fmt, Cow::Owned(_)));
+
+    let t_fmt = overlay.get_str_cow("LC_TIME", "T_FMT", en_US::LC_TIME::T_FMT);
+    assert_eq!(t_fmt, Cow::Borrowed(en_US::LC_TIME::T_FMT));
+    assert!(matches!(t_fmt, Cow::Borrowed(_)));
+
+    let title = overlay.get_opt_str_cow(
+        "LC_IDENTIFICATION",
+        "TITLE",
+        en_US::LC_IDENTIFICATION::TITLE,
+    );
+    assert!(matches!(title, Some(Cow::Borrowed(_))));
+
+    assert_eq!(
+        overlay.remove("LC_TIME", "D_FMT").as_deref(),
+        Some("%Y-%m-%d")
+    );
+    assert_eq!(overlay.get("LC_TIME", "D_FMT"), None);
+}
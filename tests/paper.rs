@@ -0,0 +1,14 @@
+use pure_rust_locales::{de_CH, de_DE, en_CA, en_US, POSIX};
+
+#[test]
+fn paper_size() {
+    assert_eq!(en_US::LC_PAPER::HEIGHT, 279);
+    assert_eq!(en_US::LC_PAPER::WIDTH, 216);
+    assert_eq!(de_DE::LC_PAPER::HEIGHT, 297);
+    assert_eq!(de_DE::LC_PAPER::WIDTH, 210);
+    assert_eq!(POSIX::LC_PAPER::HEIGHT, 297);
+
+    // copied from other locales
+    assert_eq!(en_CA::LC_PAPER::HEIGHT, 279);
+    assert_eq!(de_CH::LC_PAPER::HEIGHT, 297);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "serde")]
+
+use pure_rust_locales::Locale;
+
+#[test]
+fn round_trip() {
+    for locale in [
+        Locale::POSIX,
+        Locale::en_US,
+        Locale::de_DE_euro,
+        Locale::ja_JP,
+        Locale::sr_RS_latin,
+    ] {
+        let json = serde_json::to_string(&locale).unwrap();
+        assert_eq!(json, format!("\"{}\"", locale));
+        assert_eq!(serde_json::from_str::<Locale>(&json).unwrap(), locale);
+    }
+    assert_eq!(
+        serde_json::to_string(&Locale::de_DE_euro).unwrap(),
+        "\"de_DE@euro\""
+    );
+}
+
+#[test]
+fn unknown_locale() {
+    let err = serde_json::from_str::<Locale>("\"xx_XX\"").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid value: string \"xx_XX\", expected the name of a locale"));
+    assert!(serde_json::from_str::<Locale>("1").is_err());
+}
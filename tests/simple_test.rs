@@ -18,3 +18,36 @@ fn parsing_locales() {
     let locale: Locale = locale_string.as_str().try_into().unwrap();
     assert_eq!(locale, Locale::fr_BE);
 }
+
+#[test]
+fn deduplicated_categories() {
+    use pure_rust_locales::{de_AT, de_DE, de_DE_euro};
+
+    // these categories are identical and only generated once
+    assert_eq!(de_DE_euro::LC_TIME::MON, de_DE::LC_TIME::MON);
+    assert_eq!(de_DE_euro::LC_TIME::D_FMT, de_DE::LC_TIME::D_FMT);
+    assert_eq!(de_AT::LC_NUMERIC::DECIMAL_POINT, ",");
+    assert_eq!(
+        de_AT::LC_NUMERIC::THOUSANDS_SEP,
+        de_DE::LC_NUMERIC::THOUSANDS_SEP
+    );
+    assert_eq!(de_AT::LC_NUMERIC::GROUPING, de_DE::LC_NUMERIC::GROUPING);
+}
+
+#[test]
+fn shared_slices() {
+    use pure_rust_locales::{en_GB, en_US, POSIX};
+
+    // these slices are shared with other locales
+    assert_eq!(en_US::LC_TIME::AM_PM, &["AM", "PM"]);
+    assert_eq!(
+        POSIX::LC_TIME::ABDAY,
+        &["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+    );
+    assert_eq!(en_GB::LC_NUMERIC::GROUPING, &[3, 3]);
+    assert_eq!(POSIX::LC_NUMERIC::GROUPING, &[-1]);
+    assert_eq!(
+        en_US::LC_IDENTIFICATION::CATEGORY.unwrap()[0],
+        &["i18n:2012", "LC_IDENTIFICATION"]
+    );
+}
@@ -0,0 +1,50 @@
+use pure_rust_locales::{de_DE, en_US, POSIX};
+
+#[test]
+fn dialing_codes() {
+    assert_eq!(de_DE::LC_TELEPHONE::country_calling_code(), "49");
+    assert_eq!(de_DE::LC_TELEPHONE::international_dial_prefix(), "00");
+    assert_eq!(en_US::LC_TELEPHONE::country_calling_code(), "1");
+    assert_eq!(en_US::LC_TELEPHONE::international_dial_prefix(), "11");
+
+    assert_eq!(POSIX::LC_TELEPHONE::country_calling_code(), "");
+    assert_eq!(POSIX::LC_TELEPHONE::international_dial_prefix(), "");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn format_domestic() {
+    use pure_rust_locales::fr_FR;
+
+    assert_eq!(
+        en_US::LC_TELEPHONE::format_domestic("212", "5551234"),
+        "(212) 5551234"
+    );
+    assert_eq!(
+        de_DE::LC_TELEPHONE::format_domestic("30", "1234567"),
+        "030 1234567"
+    );
+    assert_eq!(
+        fr_FR::LC_TELEPHONE::format_domestic("1", "23456789"),
+        "1 23456789"
+    );
+    assert_eq!(
+        POSIX::LC_TELEPHONE::format_domestic("30", "1234567"),
+        "30 1234567"
+    );
+    assert_eq!(
+        POSIX::LC_TELEPHONE::format_domestic("", "1234567"),
+        "1234567"
+    );
+}
+
+#[test]
+fn formats() {
+    assert_eq!(de_DE::LC_TELEPHONE::TEL_INT_FMT, "+%c %a %l");
+    assert_eq!(de_DE::LC_TELEPHONE::TEL_DOM_FMT, "%A %l");
+    assert_eq!(de_DE::LC_TELEPHONE::INT_SELECT, "00");
+    assert_eq!(de_DE::LC_TELEPHONE::INT_PREFIX, "49");
+
+    assert_eq!(POSIX::LC_TELEPHONE::TEL_INT_FMT, "+%c %a %l");
+    assert_eq!(POSIX::LC_TELEPHONE::TEL_DOM_FMT, "");
+}
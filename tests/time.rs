@@ -0,0 +1,288 @@
+use pure_rust_locales::{ar_EG, de_DE, en_US, fr_FR, Locale, Month, Weekday, ERA_LOCALES, POSIX};
+
+#[test]
+fn ordered_days() {
+    assert_eq!(en_US::LC_TIME::ordered_abday()[0], "Sun");
+    assert_eq!(en_US::LC_TIME::ordered_day()[6], "Saturday");
+    assert_eq!(de_DE::LC_TIME::ordered_abday()[0], "Mo");
+    assert_eq!(de_DE::LC_TIME::ordered_day()[0], "Montag");
+    assert_eq!(de_DE::LC_TIME::ordered_day()[6], "Sonntag");
+}
+
+#[test]
+fn source_order() {
+    use pure_rust_locales::{fr_FR, ja_JP, ru_RU};
+
+    assert_eq!(en_US::LC_TIME::MON[0], "January");
+    assert_eq!(en_US::LC_TIME::MON[11], "December");
+    assert_eq!(en_US::LC_TIME::DAY[0], "Sunday");
+    assert_eq!(de_DE::LC_TIME::MON[0], "Januar");
+    assert_eq!(de_DE::LC_TIME::ABMON[2], "Mär");
+    assert_eq!(fr_FR::LC_TIME::MON[0], "janvier");
+    assert_eq!(fr_FR::LC_TIME::MON[11], "décembre");
+    assert_eq!(ja_JP::LC_TIME::MON[0], "1月");
+    assert_eq!(ja_JP::LC_TIME::MON[11], "12月");
+    assert_eq!(ru_RU::LC_TIME::DAY[1], "Понедельник");
+}
+
+#[test]
+fn format_newtypes() {
+    use pure_rust_locales::{DateFormat, DateTimeFormat, FormatToken, TimeFormat};
+
+    let d_fmt: DateFormat = de_DE::LC_TIME::d_fmt();
+    assert_eq!(d_fmt.as_str(), "%d.%m.%Y");
+    assert_eq!(
+        d_fmt.tokens().collect::<Vec<_>>(),
+        [
+            FormatToken::Specifier("%d"),
+            FormatToken::Literal("."),
+            FormatToken::Specifier("%m"),
+            FormatToken::Literal("."),
+            FormatToken::Specifier("%Y"),
+        ],
+    );
+
+    let t_fmt: TimeFormat = en_US::LC_TIME::t_fmt();
+    assert_eq!(t_fmt.as_str(), en_US::LC_TIME::T_FMT);
+    assert_eq!(t_fmt.tokens().next(), Some(FormatToken::Specifier("%I")));
+
+    let d_t_fmt: DateTimeFormat = en_US::LC_TIME::d_t_fmt();
+    assert_eq!(d_t_fmt.as_str(), en_US::LC_TIME::D_T_FMT);
+
+    let tokens = DateFormat("%-d %Ey年 %%").tokens().collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        [
+            FormatToken::Specifier("%-d"),
+            FormatToken::Literal(" "),
+            FormatToken::Specifier("%Ey"),
+            FormatToken::Literal("年 "),
+            FormatToken::Specifier("%%"),
+        ],
+    );
+}
+
+#[test]
+fn fixed_length_names() {
+    let day: &[&str; 7] = en_US::LC_TIME::DAY;
+    assert_eq!(day[0], "Sunday");
+    let abmon: &[&str; 12] = fr_FR::LC_TIME::ABMON;
+    assert_eq!(abmon[11], "déc.");
+    assert_eq!(de_DE::LC_TIME::MON.len(), 12);
+}
+
+#[test]
+fn standalone_months() {
+    use pure_rust_locales::ru_RU;
+
+    assert_eq!(ru_RU::LC_TIME::ABMON[4], "мая");
+    assert_eq!(ru_RU::LC_TIME::standalone_abmon(5), Some("май"));
+    assert_eq!(ru_RU::LC_TIME::standalone_abmon(1), Some("янв"));
+    assert_eq!(ru_RU::LC_TIME::standalone_mon(1), Some("Январь"));
+    assert_eq!(ru_RU::LC_TIME::standalone_abmon(0), None);
+    assert_eq!(ru_RU::LC_TIME::standalone_abmon(13), None);
+    // no `AB_ALT_MON`
+    assert_eq!(en_US::LC_TIME::standalone_abmon(12), Some("Dec"));
+    assert_eq!(en_US::LC_TIME::standalone_mon(12), Some("December"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn month_titlecase() {
+    assert_eq!(fr_FR::LC_TIME::MON[0], "janvier");
+    assert_eq!(
+        fr_FR::LC_TIME::month_titlecase(1).as_deref(),
+        Some("Janvier")
+    );
+    assert_eq!(fr_FR::LC_TIME::month_titlecase(8).as_deref(), Some("Août"));
+    assert_eq!(
+        en_US::LC_TIME::month_titlecase(12).as_deref(),
+        Some("December")
+    );
+    assert_eq!(en_US::LC_TIME::month_titlecase(13), None);
+}
+
+#[test]
+fn am_pm() {
+    use pure_rust_locales::{de_DE, POSIX};
+
+    assert_eq!(en_US::LC_TIME::am_pm(), Some(["AM", "PM"]));
+    assert_eq!(POSIX::LC_TIME::am_pm(), Some(["AM", "PM"]));
+    assert_eq!(de_DE::LC_TIME::am_pm(), None);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn am_pm_lower() {
+    use pure_rust_locales::de_DE;
+
+    assert_eq!(
+        en_US::LC_TIME::am_pm_lower(),
+        Some(["am".to_string(), "pm".to_string()])
+    );
+    assert_eq!(de_DE::LC_TIME::am_pm_lower(), None);
+}
+
+#[test]
+fn uses_iso8601_weeks() {
+    assert!(de_DE::LC_TIME::uses_iso8601_weeks());
+    assert!(fr_FR::LC_TIME::uses_iso8601_weeks());
+    assert!(!en_US::LC_TIME::uses_iso8601_weeks());
+    assert!(!POSIX::LC_TIME::uses_iso8601_weeks());
+}
+
+#[test]
+fn first_weekday_sunday0() {
+    assert_eq!(en_US::LC_TIME::first_weekday_sunday0(), 0);
+    assert_eq!(fr_FR::LC_TIME::first_weekday_sunday0(), 1);
+    assert_eq!(ar_EG::LC_TIME::first_weekday_sunday0(), 6);
+    assert_eq!(POSIX::LC_TIME::first_weekday_sunday0(), 0);
+}
+
+#[test]
+fn weekday_and_month_order() {
+    assert!(Month::January < Month::December);
+    assert!(Month::May < Month::June);
+    assert_eq!(Month::March as usize, 3);
+    assert!(Weekday::Sunday < Weekday::Monday);
+    assert!(Weekday::Friday < Weekday::Saturday);
+    assert_eq!(
+        en_US::LC_TIME::DAY[Weekday::Wednesday as usize],
+        "Wednesday"
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn date_fmt_with_timezone() {
+    use pure_rust_locales::af_ZA;
+
+    assert_eq!(
+        de_DE::LC_TIME::date_fmt_with_timezone(Some("CET"), Some("+0100")),
+        "%a %-d. %b %H:%M:%S CET %Y"
+    );
+    assert_eq!(
+        de_DE::LC_TIME::date_fmt_with_timezone(None, None),
+        "%a %-d. %b %H:%M:%S  %Y"
+    );
+    // no `DATE_FMT`
+    assert_eq!(af_ZA::LC_TIME::DATE_FMT, None);
+    assert_eq!(
+        af_ZA::LC_TIME::date_fmt_with_timezone(Some("UTC"), None),
+        "%a %b %e %H:%M:%S UTC %Y"
+    );
+}
+
+#[test]
+fn era_locales() {
+    assert!(ERA_LOCALES.contains(&Locale::ja_JP));
+    assert!(ERA_LOCALES.contains(&Locale::th_TH));
+    assert!(!ERA_LOCALES.contains(&Locale::en_US));
+    assert!(!ERA_LOCALES.contains(&Locale::POSIX));
+}
+
+#[test]
+fn eras() {
+    use pure_rust_locales::{ja_JP, Era, EraDate};
+
+    let eras: Vec<Era> = ja_JP::LC_TIME::eras().collect();
+    assert_eq!(eras.len(), ja_JP::LC_TIME::ERA_ENTRIES.len());
+    assert_eq!(eras[0].name, "紀元前");
+    assert_eq!(eras[0].end, EraDate::MinusInfinity);
+    assert!(eras
+        .windows(2)
+        .all(|x| x[0].start.min(x[0].end) <= x[1].start.min(x[1].end)));
+
+    let reiwa = eras.last().unwrap();
+    assert_eq!(reiwa.name, "令和");
+    assert_eq!(reiwa.direction, '+');
+    assert_eq!(reiwa.offset, 2);
+    assert_eq!(
+        reiwa.start,
+        EraDate::Date {
+            year: 2020,
+            month: 1,
+            day: 1
+        }
+    );
+    assert_eq!(reiwa.end, EraDate::PlusInfinity);
+    assert_eq!(reiwa.format, "%EC%Ey年");
+
+    let heisei: Vec<_> = eras.iter().filter(|x| x.name == "平成").collect();
+    assert_eq!(
+        heisei[0].start,
+        EraDate::Date {
+            year: 1989,
+            month: 1,
+            day: 8
+        }
+    );
+
+    assert_eq!(en_US::LC_TIME::eras().count(), 0);
+}
+
+#[test]
+fn era_entries_match_era() {
+    use pure_rust_locales::ja_JP;
+
+    let raw = ja_JP::LC_TIME::ERA.unwrap();
+    assert_eq!(raw.len(), ja_JP::LC_TIME::ERA_ENTRIES.len());
+    // `ERA` keeps the order of glibc (the current era first), `ERA_ENTRIES` is sorted
+    assert!(raw[0].contains("令和"));
+    assert_eq!(ja_JP::LC_TIME::ERA_ENTRIES.last().unwrap().name, "令和");
+    for raw in raw {
+        let fields: Vec<&str> = raw.split(':').collect();
+        assert_eq!(fields.len(), 6);
+        assert!(ja_JP::LC_TIME::ERA_ENTRIES.iter().any(|era| {
+            fields[0].starts_with(era.direction)
+                && fields[1].parse::<i32>().ok() == Some(era.offset)
+                && fields[4] == era.name
+                && fields[5] == era.format
+        }));
+    }
+
+    let meiji = ja_JP::LC_TIME::eras().find(|x| x.name == "明治").unwrap();
+    assert_eq!(meiji.format, "%EC%Ey年");
+}
+
+#[test]
+fn alt_digits() {
+    use pure_rust_locales::{fa_IR, ja_JP};
+
+    // the Persian zero, padded to two digits
+    assert_eq!(fa_IR::LC_TIME::ALT_DIGITS[0], "۰۰");
+    assert!(fa_IR::LC_TIME::ALT_DIGITS[0]
+        .chars()
+        .all(|x| x == '\u{6f0}'));
+    assert_eq!(fa_IR::LC_TIME::alt_digit(0), Some("۰۰"));
+    assert_eq!(fa_IR::LC_TIME::alt_digit(12), Some("۱۲"));
+    assert_eq!(ja_JP::LC_TIME::alt_digit(10), Some("十"));
+    // locales without `ALT_DIGITS` have an empty table
+    assert!(en_US::LC_TIME::ALT_DIGITS.is_empty());
+    assert_eq!(en_US::LC_TIME::alt_digit(0), None);
+    assert_eq!(fa_IR::LC_TIME::alt_digit(100), None);
+}
+
+#[test]
+fn get() {
+    use pure_rust_locales::{get, DataValue, Locale, LocaleValue};
+
+    assert_eq!(
+        get(Locale::en_US, "LC_TIME", "ABDAY"),
+        Some(DataValue::StrSlice(&[
+            "Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"
+        ]))
+    );
+    assert_eq!(
+        get(Locale::en_US, "LC_TIME", "D_FMT"),
+        Some(LocaleValue::Str(en_US::LC_TIME::D_FMT))
+    );
+    assert_eq!(
+        get(Locale::en_US, "LC_MEASUREMENT", "MEASUREMENT"),
+        Some(DataValue::Int(2))
+    );
+    // `ERA` is `None` for `en_US`
+    assert_eq!(get(Locale::en_US, "LC_TIME", "ERA"), None);
+    assert_eq!(get(Locale::en_US, "LC_TIME", "abday"), None);
+    assert_eq!(get(Locale::en_US, "LC_FOO", "ABDAY"), None);
+}
@@ -0,0 +1,49 @@
+//! Run with only two locales enabled:
+//!
+//! ```sh
+//! cargo test --test two_locales --no-default-features \
+//!     --features root-modules,all-categories,alloc,locale-en_US,locale-ja_JP
+//! ```
+#![cfg(all(
+    feature = "locale-en_US",
+    feature = "locale-ja_JP",
+    not(feature = "locale-de_DE")
+))]
+
+use core::convert::TryFrom;
+use pure_rust_locales::{en_US, ja_JP, Locale, ERA_LOCALES};
+
+#[test]
+fn enabled_locales() {
+    assert_eq!(Locale::ALL, [Locale::POSIX, Locale::en_US, Locale::ja_JP]);
+    assert_eq!(ERA_LOCALES, [Locale::ja_JP]);
+    assert!(Locale::try_from("de_DE").is_err());
+    assert_eq!(Locale::try_from("ja_JP").ok(), Some(Locale::ja_JP));
+    assert_eq!(Locale::en_US.is_derived_from(), None);
+    assert_eq!(Locale::COUNT, 3);
+    assert_eq!(Locale::ja_JP.variant_index(), 2);
+}
+
+#[test]
+fn shared_categories() {
+    // `LC_NUMERIC` of `en_US` is the same as the one of `dv_MV`, which is not enabled
+    assert_eq!(en_US::LC_NUMERIC::DECIMAL_POINT, ".");
+    assert_eq!(en_US::LC_MEASUREMENT::MEASUREMENT, 2);
+    assert_eq!(en_US::LC_TIME::D_FMT, "%m/%d/%Y");
+    assert_eq!(
+        ja_JP::LC_TIME::eras().count(),
+        ja_JP::LC_TIME::ERA_ENTRIES.len()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn items() {
+    assert!(Locale::ja_JP
+        .to_posix_source("LC_TIME")
+        .starts_with("LC_TIME\n"));
+    assert_eq!(
+        Locale::closest_by_distance("en_UK").map(|x| x.0),
+        Some(Locale::en_US)
+    );
+}